@@ -0,0 +1,83 @@
+// Times a cargo build of each generated enum on its own, in a scratch crate
+// that depends on enumn. Dependencies are built once up front so that each
+// measurement covers only the derive's expansion and the compilation of the
+// code it generates.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+const ENUMS: &[(&str, &str)] = &[
+    ("dense", include_str!(concat!(env!("OUT_DIR"), "/Dense.rs"))),
+    (
+        "dense match",
+        include_str!(concat!(env!("OUT_DIR"), "/DenseMatch.rs")),
+    ),
+    (
+        "binary_search",
+        include_str!(concat!(env!("OUT_DIR"), "/Sparse.rs")),
+    ),
+    (
+        "sparse match",
+        include_str!(concat!(env!("OUT_DIR"), "/SparseMatch.rs")),
+    ),
+];
+
+const PROFILES: &[&str] = &["dev", "release"];
+
+fn build(dir: &Path, profile: &str) -> Duration {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let start = Instant::now();
+    let status = Command::new(cargo)
+        .args(["build", "--quiet", "--profile", profile])
+        .current_dir(dir)
+        .env("CARGO_INCREMENTAL", "0")
+        .env_remove("RUSTFLAGS")
+        .status()
+        .unwrap();
+    let elapsed = start.elapsed();
+    assert!(status.success());
+    elapsed
+}
+
+fn main() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("compile");
+    let enumn = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let path = enumn.to_str().unwrap();
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\n\
+             name = \"enumn-compile\"\n\
+             version = \"0.0.0\"\n\
+             edition = \"2021\"\n\
+             publish = false\n\
+             \n\
+             [dependencies]\n\
+             enumn = {{ path = {path:?} }}\n\
+             \n\
+             [workspace]\n",
+        ),
+    )
+    .unwrap();
+    if let Ok(lockfile) = fs::read(enumn.join("Cargo.lock")) {
+        fs::write(dir.join("Cargo.lock"), lockfile).unwrap();
+    }
+
+    let lib = dir.join("src").join("lib.rs");
+    fs::write(&lib, "").unwrap();
+    for profile in PROFILES {
+        build(&dir, profile);
+    }
+
+    for profile in PROFILES {
+        for (name, code) in ENUMS {
+            fs::write(&lib, code).unwrap();
+            let elapsed = build(&dir, profile);
+            println!("{name:<14} {profile:<8} {elapsed:>10.3?}");
+        }
+    }
+}
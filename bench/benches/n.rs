@@ -0,0 +1,50 @@
+#![allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+
+use enumn_bench::{Dense, DenseMatch, Sparse, SparseMatch, DENSE, SPARSE};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 10_000;
+
+// Every discriminant plus as many values that do not correspond to a variant,
+// in an order that does not let the branch predictor settle.
+fn inputs(discriminants: &[u32]) -> Vec<u32> {
+    let mut state = 0x2545_f491_u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+    let mut inputs = discriminants.to_vec();
+    for &discriminant in discriminants {
+        inputs.push(discriminant.wrapping_add(discriminants.len() as u32));
+    }
+    for i in (1..inputs.len()).rev() {
+        inputs.swap(i, next() as usize % (i + 1));
+    }
+    inputs
+}
+
+fn bench<E>(name: &str, inputs: &[u32], n: fn(u32) -> Option<E>) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for &value in inputs {
+            black_box(n(black_box(value)));
+        }
+    }
+    let elapsed = start.elapsed();
+    let calls = f64::from(ITERATIONS) * inputs.len() as f64;
+    let per_call = elapsed.as_secs_f64() * 1e9 / calls;
+    println!("{name:<14} {elapsed:>10.3?} {per_call:>6.2} ns/call");
+}
+
+fn main() {
+    let dense = inputs(DENSE);
+    bench("dense", &dense, Dense::n);
+    bench("dense match", &dense, DenseMatch::n);
+
+    let sparse = inputs(SPARSE);
+    bench("binary_search", &sparse, Sparse::n);
+    bench("sparse match", &sparse, SparseMatch::n);
+}
@@ -0,0 +1,52 @@
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+    let mut out = String::new();
+
+    // Discriminants 0..256.
+    let dense: Vec<u32> = (0..256).collect();
+    write_enum(out_dir, &mut out, "Dense", "dense", &dense);
+    write_enum(out_dir, &mut out, "DenseMatch", "match", &dense);
+
+    // 300 discriminants scattered over the 32-bit space.
+    let mut sparse = Vec::new();
+    let mut state = 0x9e37_79b9_u32;
+    while sparse.len() < 300 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        if !sparse.contains(&state) {
+            sparse.push(state);
+        }
+    }
+    write_enum(out_dir, &mut out, "Sparse", "binary_search", &sparse);
+    write_enum(out_dir, &mut out, "SparseMatch", "match", &sparse);
+
+    fs::write(out_dir.join("enums.rs"), out).unwrap();
+}
+
+// Appends the enum to `out`, and also writes it to a file of its own for the
+// compile time benchmark to build in isolation.
+fn write_enum(out_dir: &Path, out: &mut String, name: &str, strategy: &str, discriminants: &[u32]) {
+    let start = out.len();
+    writeln!(out, "#[derive(Copy, Clone, enumn::N)]").unwrap();
+    writeln!(out, "#[repr(u32)]").unwrap();
+    writeln!(out, "#[enumn(strategy = {strategy:?})]").unwrap();
+    writeln!(out, "pub enum {name} {{").unwrap();
+    for (i, discriminant) in discriminants.iter().enumerate() {
+        writeln!(out, "    V{i} = {discriminant},").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(
+        out,
+        "pub const {}: &[u32] = &{discriminants:?};",
+        name.to_uppercase(),
+    )
+    .unwrap();
+    fs::write(out_dir.join(format!("{name}.rs")), &out[start..]).unwrap();
+}
@@ -0,0 +1,9 @@
+//! Large enums for comparing the code generated by `#[derive(enumn::N)]`.
+//!
+//! Run with `cargo bench -p enumn-bench --bench n` to compare the runtime of
+//! `n`, and `cargo bench -p enumn-bench --bench compile` to compare how long
+//! each enum takes to build.
+
+#![allow(clippy::unreadable_literal)]
+
+include!(concat!(env!("OUT_DIR"), "/enums.rs"));
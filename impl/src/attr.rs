@@ -0,0 +1,370 @@
+use crate::case::RenameRule;
+use crate::literal::Alias;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Error, Ident, LitStr, Meta, Path, Result, Token, Variant};
+
+pub enum Repr {
+    // #[repr(u8)] and friends, possibly alongside #[repr(C)]. The flag is set
+    // if #[repr(align(N))] or #[repr(packed(N))] makes the layout of the enum
+    // differ from that of the primitive.
+    Primitive(Ident, bool),
+    // #[repr(C)] alone, which has the size of the platform's C int.
+    C,
+}
+
+impl Repr {
+    // The type of the discriminant. The C int comes from the runtime crate,
+    // because it is only in core since Rust 1.64.
+    pub fn ty(&self, krate: &Path) -> TokenStream {
+        match self {
+            Repr::Primitive(ident, _aligned) => quote!(::core::primitive::#ident),
+            Repr::C => quote!(#krate::__private::c_int),
+        }
+    }
+}
+
+// Parse repr attributes like #[repr(u16)], #[repr(C, u8)], #[repr(C)].
+pub fn repr(attrs: &[Attribute]) -> Result<Option<Repr>> {
+    let mut primitive: Option<Ident> = None;
+    let mut c = false;
+    let mut aligned = false;
+
+    for attr in attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        let hints = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for hint in hints {
+            let ident = match hint {
+                Meta::Path(path) => match path.get_ident() {
+                    Some(ident) => ident.clone(),
+                    None => continue,
+                },
+                // align(N), packed(N)
+                Meta::List(list) => {
+                    aligned |= list.path.is_ident("align") || list.path.is_ident("packed");
+                    continue;
+                }
+                Meta::NameValue(_) => continue,
+            };
+            match ident.to_string().as_str() {
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+                | "i128" | "isize" => {
+                    if let Some(prev) = &primitive {
+                        let msg = format!(
+                            "enumn: conflicting representation hints `{}` and `{}`",
+                            prev, ident,
+                        );
+                        return Err(Error::new(ident.span(), msg));
+                    }
+                    primitive = Some(ident);
+                }
+                "C" => c = true,
+                "packed" => aligned = true,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(match primitive {
+        Some(primitive) => Some(Repr::Primitive(primitive, aligned)),
+        None if c => Some(Repr::C),
+        None => None,
+    })
+}
+
+pub struct ContainerAttrs {
+    pub krate: Option<Path>,
+    pub repr_type: Option<Ident>,
+    pub iter: Option<Ident>,
+    pub iter_order: Option<(IterOrder, Span)>,
+    pub rename_all: Option<RenameRule>,
+    pub try_n: Option<Ident>,
+    pub try_from: Option<Ident>,
+    pub value: Option<Ident>,
+    pub from_repr_trait: Option<Ident>,
+    pub name: Option<Ident>,
+    pub variants: Option<Ident>,
+    pub discriminant: Option<Ident>,
+    pub from_str: Option<Ident>,
+    pub strategy: Option<(Strategy, Span)>,
+    pub exhaustive: Option<Ident>,
+    pub kind: Option<Ident>,
+    pub default_fields: Option<Ident>,
+    pub set: Option<(Option<Ident>, Span)>,
+}
+
+pub enum IterOrder {
+    Discriminant,
+    Declaration,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Strategy {
+    Match,
+    Dense,
+    BinarySearch,
+}
+
+pub fn container_attrs(attrs: &[Attribute]) -> Result<ContainerAttrs> {
+    let mut container = ContainerAttrs {
+        krate: None,
+        repr_type: None,
+        iter: None,
+        iter_order: None,
+        rename_all: None,
+        try_n: None,
+        try_from: None,
+        value: None,
+        from_repr_trait: None,
+        name: None,
+        variants: None,
+        discriminant: None,
+        from_str: None,
+        strategy: None,
+        exhaustive: None,
+        kind: None,
+        default_fields: None,
+        set: None,
+    };
+    for attr in attrs {
+        if !attr.path().is_ident("enumn") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                if container.krate.is_some() {
+                    return Err(meta.error("duplicate enumn(crate) attribute"));
+                }
+                container.krate = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("repr_type") {
+                if container.repr_type.is_some() {
+                    return Err(meta.error("duplicate enumn(repr_type) attribute"));
+                }
+                let ty: Ident = meta.value()?.parse()?;
+                if ty != "i64" && ty != "i128" {
+                    return Err(Error::new(
+                        ty.span(),
+                        "enumn: repr_type must be i64 or i128",
+                    ));
+                }
+                container.repr_type = Some(ty);
+                Ok(())
+            } else if meta.path.is_ident("iter") {
+                if container.iter.is_some() {
+                    return Err(meta.error("duplicate enumn(iter) attribute"));
+                }
+                container.iter = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("iter_order") {
+                if container.iter_order.is_some() {
+                    return Err(meta.error("duplicate enumn(iter_order) attribute"));
+                }
+                let order: LitStr = meta.value()?.parse()?;
+                let span = meta.path.span();
+                container.iter_order = match order.value().as_str() {
+                    "discriminant" => Some((IterOrder::Discriminant, span)),
+                    "declaration" => Some((IterOrder::Declaration, span)),
+                    _ => {
+                        let msg = "enumn: iter_order must be \"discriminant\" or \"declaration\"";
+                        return Err(Error::new(order.span(), msg));
+                    }
+                };
+                Ok(())
+            } else if meta.path.is_ident("rename_all") {
+                if container.rename_all.is_some() {
+                    return Err(meta.error("duplicate enumn(rename_all) attribute"));
+                }
+                let rule: LitStr = meta.value()?.parse()?;
+                match RenameRule::from_str(&rule.value()) {
+                    Some(rename_all) => container.rename_all = Some(rename_all),
+                    None => {
+                        let msg = format!(
+                            "enumn: unknown rename rule, expected one of {}",
+                            RenameRule::expected(),
+                        );
+                        return Err(Error::new(rule.span(), msg));
+                    }
+                }
+                Ok(())
+            } else if meta.path.is_ident("try_n") {
+                if container.try_n.is_some() {
+                    return Err(meta.error("duplicate enumn(try_n) attribute"));
+                }
+                container.try_n = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("try_from") {
+                if container.try_from.is_some() {
+                    return Err(meta.error("duplicate enumn(try_from) attribute"));
+                }
+                container.try_from = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("value") {
+                if container.value.is_some() {
+                    return Err(meta.error("duplicate enumn(value) attribute"));
+                }
+                container.value = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("from_repr_trait") {
+                if container.from_repr_trait.is_some() {
+                    return Err(meta.error("duplicate enumn(from_repr_trait) attribute"));
+                }
+                container.from_repr_trait = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                if container.name.is_some() {
+                    return Err(meta.error("duplicate enumn(name) attribute"));
+                }
+                container.name = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("variants") {
+                if container.variants.is_some() {
+                    return Err(meta.error("duplicate enumn(variants) attribute"));
+                }
+                container.variants = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("discriminant") {
+                if container.discriminant.is_some() {
+                    return Err(meta.error("duplicate enumn(discriminant) attribute"));
+                }
+                container.discriminant = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("from_str") {
+                if container.from_str.is_some() {
+                    return Err(meta.error("duplicate enumn(from_str) attribute"));
+                }
+                container.from_str = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("kind") {
+                if container.kind.is_some() {
+                    return Err(meta.error("duplicate enumn(kind) attribute"));
+                }
+                container.kind = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default_fields") {
+                if container.default_fields.is_some() {
+                    return Err(meta.error("duplicate enumn(default_fields) attribute"));
+                }
+                container.default_fields = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("set") {
+                if container.set.is_some() {
+                    return Err(meta.error("duplicate enumn(set) attribute"));
+                }
+                let name = if meta.input.peek(Token![=]) {
+                    Some(meta.value()?.parse()?)
+                } else {
+                    None
+                };
+                container.set = Some((name, meta.path.span()));
+                Ok(())
+            } else if meta.path.is_ident("exhaustive") {
+                if container.exhaustive.is_some() {
+                    return Err(meta.error("duplicate enumn(exhaustive) attribute"));
+                }
+                container.exhaustive = meta.path.get_ident().cloned();
+                Ok(())
+            } else if meta.path.is_ident("strategy") {
+                if container.strategy.is_some() {
+                    return Err(meta.error("duplicate enumn(strategy) attribute"));
+                }
+                let strategy: LitStr = meta.value()?.parse()?;
+                let span = strategy.span();
+                container.strategy = match strategy.value().as_str() {
+                    "match" => Some((Strategy::Match, span)),
+                    "dense" => Some((Strategy::Dense, span)),
+                    "binary_search" => Some((Strategy::BinarySearch, span)),
+                    _ => {
+                        let msg =
+                            "enumn: strategy must be \"match\", \"dense\", or \"binary_search\"";
+                        return Err(Error::new(span, msg));
+                    }
+                };
+                Ok(())
+            } else {
+                Err(meta.error("unsupported enumn attribute"))
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+pub struct VariantAttrs {
+    pub default: bool,
+    pub other: bool,
+    pub rename: Option<LitStr>,
+    pub aliases: Vec<LitStr>,
+    pub values: Vec<Alias>,
+}
+
+pub fn variant_attrs(variant: &Variant) -> Result<VariantAttrs> {
+    let mut attrs = VariantAttrs {
+        default: false,
+        other: false,
+        rename: None,
+        aliases: Vec::new(),
+        values: Vec::new(),
+    };
+
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("enumn") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                if attrs.default {
+                    return Err(meta.error("duplicate enumn(default) attribute"));
+                }
+                attrs.default = true;
+                Ok(())
+            } else if meta.path.is_ident("other") {
+                if attrs.other {
+                    return Err(meta.error("duplicate enumn(other) attribute"));
+                }
+                attrs.other = true;
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                if attrs.rename.is_some() {
+                    return Err(meta.error("duplicate enumn(rename) attribute"));
+                }
+                attrs.rename = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("alias_name") {
+                attrs.aliases.push(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("alias") {
+                attrs.values.push(Alias::parse(meta.value()?)?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported enumn attribute"))
+            }
+        })?;
+    }
+
+    Ok(attrs)
+}
+
+// The container's #[enumn(...)] attributes other than `kind`, for passing on
+// to the generated kind enum.
+pub fn forward_to_kind(attrs: &[Attribute]) -> Result<Vec<TokenStream>> {
+    let mut forward = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("enumn") {
+            continue;
+        }
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        let metas: Vec<Meta> = metas
+            .into_iter()
+            .filter(|meta| !meta.path().is_ident("kind"))
+            .collect();
+        if !metas.is_empty() {
+            forward.push(quote!(#[enumn(#(#metas),*)]));
+        }
+    }
+    Ok(forward)
+}
@@ -0,0 +1,72 @@
+use self::RenameRule::*;
+
+#[derive(Copy, Clone)]
+pub enum RenameRule {
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+static RENAME_RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", LowerCase),
+    ("UPPERCASE", UpperCase),
+    ("PascalCase", PascalCase),
+    ("camelCase", CamelCase),
+    ("snake_case", SnakeCase),
+    ("SCREAMING_SNAKE_CASE", ScreamingSnakeCase),
+    ("kebab-case", KebabCase),
+    ("SCREAMING-KEBAB-CASE", ScreamingKebabCase),
+];
+
+impl RenameRule {
+    pub fn from_str(rule: &str) -> Option<Self> {
+        RENAME_RULES
+            .iter()
+            .find(|(name, _rule)| *name == rule)
+            .map(|(_name, rule)| *rule)
+    }
+
+    pub fn expected() -> String {
+        let names: Vec<String> = RENAME_RULES
+            .iter()
+            .map(|(name, _rule)| format!("{:?}", name))
+            .collect();
+        names.join(", ")
+    }
+
+    // Variant names are conventionally PascalCase already.
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            PascalCase => variant.to_owned(),
+            LowerCase => variant.to_lowercase(),
+            UpperCase => variant.to_uppercase(),
+            CamelCase => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+            SnakeCase => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.extend(ch.to_lowercase());
+                }
+                snake
+            }
+            ScreamingSnakeCase => SnakeCase.apply_to_variant(variant).to_uppercase(),
+            KebabCase => SnakeCase.apply_to_variant(variant).replace('_', "-"),
+            ScreamingKebabCase => ScreamingSnakeCase
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+}
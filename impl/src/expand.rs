@@ -0,0 +1,1515 @@
+use crate::attr::{self, Strategy};
+use crate::literal::{self, Alias, Int};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use std::fmt::Write as _;
+use syn::ext::IdentExt as _;
+use syn::punctuated::Punctuated;
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Ident, Result, Token, Variant};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        Data::Struct(data) => {
+            let msg = "enumn: input must be an enum, not a struct";
+            return Err(Error::new(data.struct_token.span, msg));
+        }
+        Data::Union(data) => {
+            let msg = "enumn: input must be an enum, not a union";
+            return Err(Error::new(data.union_token.span, msg));
+        }
+    };
+
+    let mut errors = Vec::new();
+    let repr = attr::repr(&input.attrs);
+    let container = attr::container_attrs(&input.attrs);
+    errors.extend(repr.as_ref().err().cloned());
+    errors.extend(container.as_ref().err().cloned());
+
+    if let (Ok(repr), Ok(container)) = (&repr, &container) {
+        if let Some(kind) = &container.kind {
+            return derive_kind(input, variants, repr.as_ref(), container, kind);
+        }
+    }
+
+    // Variants with data are constructed from Default::default() field values
+    // if the enum is marked #[enumn(default_fields)].
+    let default_fields = match &container {
+        Ok(container) => container.default_fields.as_ref(),
+        Err(_) => None,
+    };
+
+    // Find the catch-all variant marked #[enumn(default)] and the variant
+    // marked #[enumn(other)] that captures unknown values.
+    let mut default: Option<&Variant> = None;
+    let mut other = None;
+    let mut variant_attrs = Vec::new();
+    for variant in variants {
+        let attrs = match attr::variant_attrs(variant) {
+            Ok(attrs) => attrs,
+            Err(err) => {
+                errors.push(err);
+                continue;
+            }
+        };
+        let span = variant.ident.span();
+        if attrs.other {
+            let holds_repr = match &variant.fields {
+                Fields::Unnamed(fields) => fields.unnamed.len() == 1,
+                Fields::Named(_) | Fields::Unit => false,
+            };
+            if !holds_repr {
+                let msg = "enumn: #[enumn(other)] variant must have a single unnamed field holding the repr";
+                errors.push(Error::new(span, msg));
+            }
+            if other.is_some() {
+                let msg = "enumn: only one variant can be #[enumn(other)]";
+                errors.push(Error::new(span, msg));
+            }
+            if let Some(alias) = attrs.values.first() {
+                let msg = "enumn: #[enumn(other)] variant cannot have an alias";
+                errors.push(Error::new(alias.lo.span, msg));
+            }
+            other = Some(&variant.ident);
+        } else if default_fields.is_none() {
+            match variant.fields {
+                Fields::Unit => {}
+                Fields::Named(_) | Fields::Unnamed(_) => {
+                    let msg = "enumn: variant with data is not supported, consider #[enumn(kind = ...)] to generate a fieldless enum";
+                    errors.push(Error::new(span, msg));
+                }
+            }
+        }
+        if attrs.default {
+            if default.is_some() {
+                let msg = "enumn: only one variant can be #[enumn(default)]";
+                errors.push(Error::new(span, msg));
+            }
+            default = Some(variant);
+        }
+        variant_attrs.push(attrs);
+    }
+    if let (Some(default), Some(_)) = (default, other) {
+        let span = default.ident.span();
+        let msg = "enumn: #[enumn(default)] cannot be combined with #[enumn(other)]";
+        errors.push(Error::new(span, msg));
+    }
+    if let (Some(default_fields), Some(_)) = (default_fields, other) {
+        let span = default_fields.span();
+        let msg = "enumn: default_fields cannot be combined with #[enumn(other)]";
+        errors.push(Error::new(span, msg));
+    }
+    let has_data = variants
+        .iter()
+        .any(|variant| !matches!(variant.fields, Fields::Unit));
+
+    let (repr, container) = match (repr, container) {
+        (Ok(repr), Ok(container)) if variant_attrs.len() == variants.len() => (repr, container),
+        _ => return Err(combine(errors)),
+    };
+
+    // Path of the runtime crate, for generated code naming its items.
+    let krate = match &container.krate {
+        Some(krate) => krate.clone(),
+        None => parse_quote!(::enumn),
+    };
+
+    let (primitive_repr, aligned) = match &repr {
+        Some(attr::Repr::Primitive(ty, aligned)) => (Some(ty.clone()), *aligned),
+        _ => (None, false),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Trait bounds on the generic parameters of a const fn require Rust 1.61.
+    let const_generic = if input.generics.params.is_empty() {
+        Some(quote!(const))
+    } else {
+        None
+    };
+
+    // Without a repr, the discriminants have type isize, all values of which
+    // fit in i64. Inputs of any integer type are accepted and converted to the
+    // comparison type, with unrepresentable values simply not matching.
+    let fallible_generics;
+    let lossless_generics;
+    let n_const;
+    let param;
+    let try_from_types;
+    let from_types;
+    let repr = match repr {
+        Some(repr) => {
+            if let Some(repr_type) = container.repr_type {
+                let msg = "enumn: repr_type is only supported on enums without #[repr]";
+                errors.push(Error::new(repr_type.span(), msg));
+            }
+            let repr = repr.ty(&krate);
+            fallible_generics = None;
+            lossless_generics = None;
+            n_const = None;
+            param = quote!(value: #repr);
+            try_from_types = vec![repr.clone()];
+            from_types = try_from_types.clone();
+            repr
+        }
+        None => {
+            let repr = container
+                .repr_type
+                .unwrap_or_else(|| Ident::new("i64", Span::call_site()));
+            let lossless: &[&str] = if repr == "i128" {
+                &["i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64"]
+            } else {
+                &["i8", "i16", "i32", "i64", "u8", "u16", "u32"]
+            };
+            let types = |types: &[&str]| -> Vec<TokenStream> {
+                types
+                    .iter()
+                    .map(|ty| {
+                        let ty = Ident::new(ty, Span::call_site());
+                        quote!(::core::primitive::#ty)
+                    })
+                    .collect()
+            };
+            try_from_types = types(&[
+                "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+                "usize",
+            ]);
+            from_types = types(lossless);
+            n_const = Some(format_ident!("n_{}", repr));
+            let repr = quote!(::core::primitive::#repr);
+            fallible_generics = Some(quote!(<REPR: ::core::convert::TryInto<#repr>>));
+            lossless_generics = Some(quote!(<REPR: ::core::convert::Into<#repr>>));
+            param = quote!(value: REPR);
+            repr
+        }
+    };
+    let explicit_repr = fallible_generics.is_none();
+    let convert_or_return = |fallback: TokenStream| {
+        if explicit_repr {
+            None
+        } else {
+            Some(quote! {
+                let value: #repr = match ::core::convert::TryInto::try_into(value) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => return #fallback,
+                };
+            })
+        }
+    };
+
+    // Names used by `name` and `from_name`. Two variants that can be compiled
+    // together must not end up with the same name.
+    let mut names = Vec::new();
+    for (variant, attrs) in variants.iter().zip(&variant_attrs) {
+        let name = match &attrs.rename {
+            Some(rename) => rename.value(),
+            None => {
+                let name = variant.ident.unraw().to_string();
+                match container.rename_all {
+                    Some(rule) => rule.apply_to_variant(&name),
+                    None => name,
+                }
+            }
+        };
+        if names.contains(&name) {
+            let span = match &attrs.rename {
+                Some(rename) => rename.span(),
+                None => variant.ident.span(),
+            };
+            let msg = format!("enumn: duplicate variant name {:?}", name);
+            errors.push(Error::new(span, msg));
+        }
+        names.push(name);
+    }
+
+    // With from_str, names and aliases are compared ignoring ASCII case, so
+    // they must be distinct even after case folding.
+    if container.from_str.is_some() {
+        let mut seen = Vec::new();
+        for ((variant, attrs), name) in variants.iter().zip(&variant_attrs).zip(&names) {
+            if Some(&variant.ident) == other {
+                if let Some(alias) = attrs.aliases.first() {
+                    let msg = "enumn: #[enumn(other)] variant cannot have an alias_name";
+                    errors.push(Error::new(alias.span(), msg));
+                }
+                continue;
+            }
+            let strings = attrs
+                .aliases
+                .iter()
+                .map(|alias| (alias.value(), alias.span()))
+                .chain(Some((name.clone(), variant.ident.span())));
+            for (string, span) in strings {
+                let folded = string.to_ascii_lowercase();
+                if seen.contains(&folded) {
+                    let msg = format!("enumn: ambiguous name {:?} in from_str", string);
+                    errors.push(Error::new(span, msg));
+                }
+                seen.push(folded);
+            }
+        }
+    } else {
+        for attrs in &variant_attrs {
+            for alias in &attrs.aliases {
+                let msg = "enumn: alias_name requires #[enumn(from_str)] on the enum";
+                errors.push(Error::new(alias.span(), msg));
+            }
+        }
+    }
+
+    // Aliases must not overlap each other or any discriminant. This is checked
+    // here where the discriminants are integer literals; the remaining cases
+    // are caught by the unreachable patterns in `check_aliases` below.
+    let literals = literal::discriminants(variants);
+    let mut seen: Vec<(&Alias, &Ident)> = Vec::new();
+    for (variant, attrs) in variants.iter().zip(&variant_attrs) {
+        for alias in &attrs.values {
+            let span = alias.lo.span;
+            for (other_variant, literal) in variants.iter().zip(&literals) {
+                let overlaps = match literal {
+                    Some(discriminant) => alias.contains(*discriminant),
+                    None => false,
+                };
+                if overlaps {
+                    let msg = format!(
+                        "enumn: alias overlaps the discriminant of variant `{}`",
+                        other_variant.ident,
+                    );
+                    errors.push(Error::new(span, msg));
+                }
+            }
+            for (prev, prev_variant) in &seen {
+                if alias.overlaps(prev) {
+                    let msg = format!(
+                        "enumn: alias overlaps an alias of variant `{}`",
+                        prev_variant,
+                    );
+                    errors.push(Error::new(span, msg));
+                }
+            }
+            seen.push((alias, &variant.ident));
+        }
+    }
+
+    // Choose how `n` finds the variant. Other than a match, the strategies
+    // transmute the input into the enum once it is known to be a discriminant,
+    // which is only sound if the enum's layout is that of a primitive repr.
+    let transmutable = primitive_repr.is_some()
+        && !aligned
+        && !has_data
+        && variant_attrs.iter().all(|attrs| attrs.values.is_empty());
+    let dense = if transmutable { dense(&literals) } else { None };
+    let strategy = match container.strategy {
+        Some((Strategy::Dense, span)) if dense.is_none() => {
+            let msg = "enumn: strategy \"dense\" requires a primitive #[repr] without align or packed, and contiguous integer literal discriminants without aliases";
+            errors.push(Error::new(span, msg));
+            Strategy::Match
+        }
+        Some((Strategy::BinarySearch, span)) if !transmutable => {
+            let msg = "enumn: strategy \"binary_search\" requires a primitive #[repr] without align or packed, and no aliases";
+            errors.push(Error::new(span, msg));
+            Strategy::Match
+        }
+        Some((strategy, _span)) => strategy,
+        None if dense.is_some() && variants.len() >= DENSE_THRESHOLD => Strategy::Dense,
+        None if transmutable && variants.len() >= BINARY_SEARCH_THRESHOLD => Strategy::BinarySearch,
+        None => Strategy::Match,
+    };
+
+    // Whether every value of the repr corresponds to a variant, in which case
+    // the conversion is infallible. Only variants with literal discriminants
+    // are counted.
+    let domain = primitive_repr.as_ref().and_then(literal::domain);
+    let mut missing = None;
+    if let Some((lo, hi)) = domain {
+        let mut covered = Vec::new();
+        for (attrs, literal) in variant_attrs.iter().zip(&literals) {
+            covered.extend(literal.map(|discriminant| (discriminant, discriminant)));
+            covered.extend(
+                attrs
+                    .values
+                    .iter()
+                    .map(|alias| (alias.lo.value, alias.hi.value)),
+            );
+        }
+        missing = Some(literal::uncovered(lo, hi, covered));
+    }
+    let total =
+        default.is_none() && other.is_none() && missing.as_ref().map_or(false, Vec::is_empty);
+    if let Some(exhaustive) = &container.exhaustive {
+        if default.is_some() || other.is_some() || total {
+            // Every value is handled.
+        } else if domain.is_none() {
+            let msg = "enumn: exhaustive requires a #[repr] of a fixed width integer type";
+            errors.push(Error::new(exhaustive.span(), msg));
+        } else if literals.contains(&None) {
+            let msg = "enumn: exhaustive requires every discriminant to be an integer literal";
+            errors.push(Error::new(exhaustive.span(), msg));
+        } else if let Some(missing) = &missing {
+            let mut msg = String::from("enumn: enum is not exhaustive, missing ");
+            for (i, (lo, hi)) in missing.iter().enumerate() {
+                if i == MISSING_LIMIT {
+                    let _ = write!(msg, "and {} more ranges", missing.len() - i);
+                    break;
+                }
+                if lo == hi {
+                    let _ = write!(msg, "{}, ", lo);
+                } else {
+                    let _ = write!(msg, "{}..={}, ", lo, hi);
+                }
+            }
+            let msg = msg.trim_end_matches(", ");
+            errors.push(Error::new(exhaustive.span(), msg));
+        }
+    }
+
+    if let Some((_name, span)) = &container.set {
+        if has_data {
+            let msg = "enumn: set requires an enum without data, consider #[enumn(kind = ...)] to generate a fieldless enum";
+            errors.push(Error::new(*span, msg));
+        }
+    }
+
+    if let Some(discriminant) = &container.discriminant {
+        if has_data && primitive_repr.is_none() {
+            let msg = "enumn: discriminant requires a primitive #[repr] on an enum with data";
+            errors.push(Error::new(discriminant.span(), msg));
+        }
+    }
+
+    if let Some(iter) = &container.iter {
+        if has_data && other.is_none() {
+            let msg = "enumn: iter requires an enum without data other than in an #[enumn(other)] variant";
+            errors.push(Error::new(iter.span(), msg));
+        }
+    }
+
+    if let Some((_order, span)) = &container.iter_order {
+        if container.iter.is_none() {
+            let msg = "enumn: iter_order requires #[enumn(iter)] on the enum";
+            errors.push(Error::new(*span, msg));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(combine(errors));
+    }
+
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let declare_discriminants = if has_data {
+        // An enum with data cannot be cast, so follow the language's rule for
+        // discriminants: the explicitly specified value, or one more than the
+        // previous variant.
+        let mut declare = TokenStream::new();
+        let mut next = quote!(0);
+        for variant in variants {
+            let var = &variant.ident;
+            let discriminant = match &variant.discriminant {
+                Some((_eq, expr)) => quote!(#expr),
+                None => next,
+            };
+            declare.extend(quote! {
+                const #var: #repr = #discriminant;
+            });
+            next = quote!(discriminant::#var + 1);
+        }
+        declare
+    } else {
+        // A fieldless enum can be cast to its repr directly.
+        variants
+            .iter()
+            .map(|variant| {
+                let variant = &variant.ident;
+                quote! {
+                    const #variant: #repr = #ident::#variant as #repr;
+                }
+            })
+            .collect::<TokenStream>()
+    };
+
+    // Expressions constructing each variant, and patterns matching it.
+    let construct = |variant: &Variant| {
+        let var = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote!(#ident::#var),
+            Fields::Unnamed(fields) => {
+                let values = fields
+                    .unnamed
+                    .iter()
+                    .map(|_| quote!(::core::default::Default::default()));
+                quote!(#ident::#var(#(#values),*))
+            }
+            Fields::Named(fields) => {
+                let fields = fields.named.iter().map(|field| &field.ident);
+                quote!(#ident::#var { #(#fields: ::core::default::Default::default()),* })
+            }
+        }
+    };
+    let pattern = |variant: &Variant| {
+        let var = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote!(#ident::#var),
+            Fields::Unnamed(_) => quote!(#ident::#var(..)),
+            Fields::Named(_) => quote!(#ident::#var { .. }),
+        }
+    };
+    let match_discriminants = |wrap: &dyn Fn(TokenStream) -> TokenStream| {
+        variants
+            .iter()
+            .zip(&variant_attrs)
+            .filter(|(variant, _attrs)| Some(&variant.ident) != other)
+            .map(|(variant, attrs)| {
+                let found = wrap(construct(variant));
+                let variant = &variant.ident;
+                let mut arm = quote! {
+                    discriminant::#variant => #found,
+                };
+                if !attrs.values.is_empty() {
+                    let values = &attrs.values;
+                    arm.extend(quote! {
+                        #(#values)|* => #found,
+                    });
+                }
+                arm
+            })
+            .collect::<TokenStream>()
+    };
+
+    // A match in which every alias comes before every discriminant, so that
+    // any overlap makes a pattern unreachable.
+    let check_aliases = if variant_attrs.iter().any(|attrs| !attrs.values.is_empty()) {
+        let mut arms = TokenStream::new();
+        for attrs in &variant_attrs {
+            for alias in &attrs.values {
+                arms.extend(quote!(#alias => {}));
+            }
+        }
+        for variant in variants {
+            let variant = &variant.ident;
+            arms.extend(quote_spanned!(variant.span()=> discriminant::#variant => {}));
+        }
+        Some(quote! {
+            #[allow(dead_code)]
+            #[deny(unreachable_patterns)]
+            fn check_aliases(value: #repr) {
+                match value {
+                    #arms
+                    _ => {}
+                }
+            }
+        })
+    } else {
+        None
+    };
+    let match_n = match_discriminants(&|variant| quote!(::core::option::Option::Some(#variant)));
+
+    let match_values = variants
+        .iter()
+        .map(|variant| {
+            let pattern = pattern(variant);
+            let variant = &variant.ident;
+            if Some(variant) == other {
+                quote! {
+                    #ident::#variant(value) => value,
+                }
+            } else {
+                quote! {
+                    #pattern => discriminant::#variant,
+                }
+            }
+        })
+        .collect::<TokenStream>();
+
+    // The language's discriminant, including that of the `other` variant, read
+    // without a cast so that enums with data are supported. Without a #[repr],
+    // the discriminants of an enum with data are not observable.
+    let discriminant = container.discriminant.as_ref().map(|_| {
+        let match_discriminant = variants
+            .iter()
+            .map(|variant| {
+                let pattern = pattern(variant);
+                let variant = &variant.ident;
+                quote! {
+                    #pattern => discriminant::#variant,
+                }
+            })
+            .collect::<TokenStream>();
+        quote! {
+            pub #const_generic fn discriminant(&self) -> #repr {
+                match *self {
+                    #match_discriminant
+                }
+            }
+        }
+    });
+
+    let list_variants = |element: &dyn Fn(&Ident, &str) -> TokenStream| {
+        variants
+            .iter()
+            .zip(&names)
+            .filter(|(variant, _name)| Some(&variant.ident) != other)
+            .map(|(variant, name)| {
+                let element = element(&variant.ident, name);
+                quote!(#element,)
+            })
+            .collect::<TokenStream>()
+    };
+    let list_variants_idents = list_variants(&|variant, _name| quote!(#ident::#variant));
+    let list_discriminants = list_variants(&|variant, _name| quote!(discriminant::#variant));
+    let list_names = list_variants(&|_variant, name| quote!(#name));
+
+    // Indices into VARIANTS in the order that `iter` produces them. Sorting by
+    // discriminant happens at compile time because the discriminants are not
+    // generally known to the macro.
+    let iter_order = match container.iter_order {
+        None | Some((attr::IterOrder::Discriminant, _)) => quote! {
+            let discriminants = helper::DISCRIMINANTS;
+            let mut order = [0; helper::COUNT];
+            let mut i = 0;
+            while i < order.len() {
+                let mut j = i;
+                while j > 0 && discriminants[order[j - 1]] > discriminants[i] {
+                    order[j] = order[j - 1];
+                    j -= 1;
+                }
+                order[j] = i;
+                i += 1;
+            }
+            order
+        },
+        Some((attr::IterOrder::Declaration, _)) => quote! {
+            let mut order = [0; helper::COUNT];
+            let mut i = 0;
+            while i < order.len() {
+                order[i] = i;
+                i += 1;
+            }
+            order
+        },
+    };
+    let match_copy = variants
+        .iter()
+        .map(|variant| {
+            let variant = &variant.ident;
+            if Some(variant) == other {
+                quote! {
+                    #ident::#variant(value) => #ident::#variant(value),
+                }
+            } else {
+                quote! {
+                    #ident::#variant => #ident::#variant,
+                }
+            }
+        })
+        .collect::<TokenStream>();
+
+    // Variants with data cannot be listed in a const, so VARIANTS is only
+    // generated if all variants other than `other` are units.
+    let list_variants = if has_data && other.is_none() {
+        None
+    } else {
+        Some(quote! {
+            const VARIANTS: &'static [#ident] = &[#list_variants_idents];
+        })
+    };
+    let (iter, order) = match &container.iter {
+        Some(_) => {
+            let iter = quote! {
+                pub fn iter() -> #krate::Iter<Self> {
+                    #krate::__private::iter(helper::VARIANTS, &helper::ORDER, |variant| {
+                        match *variant {
+                            #match_copy
+                        }
+                    })
+                }
+            };
+            let order = quote! {
+                const ORDER: [::core::primitive::usize; helper::COUNT] = {
+                    #iter_order
+                };
+            };
+            (Some(iter), Some(order))
+        }
+        None => (None, None),
+    };
+
+    // Associated consts are shadowed by variants of the same name, so generated
+    // code refers to the copies in `helper` instead, and the public ones are
+    // left out where a variant takes their name.
+    let public_const = |name: &str, ty: TokenStream| {
+        if container.variants.is_none()
+            || variants.iter().any(|variant| variant.ident.unraw() == name)
+        {
+            None
+        } else {
+            let name = Ident::new(name, Span::call_site());
+            Some(quote!(pub const #name: #ty = helper::#name;))
+        }
+    };
+    let public_variants = list_variants
+        .as_ref()
+        .and_then(|_| public_const("VARIANTS", quote!(&'static [Self])));
+    let public_count = public_const("COUNT", quote!(::core::primitive::usize));
+    let public_discriminants = public_const("DISCRIMINANTS", quote!(&'static [#repr]));
+
+    let match_name = variants
+        .iter()
+        .zip(&names)
+        .map(|(variant, name)| {
+            let pattern = pattern(variant);
+            quote! {
+                #pattern => #name,
+            }
+        })
+        .collect::<TokenStream>();
+    let match_from_name = variants
+        .iter()
+        .zip(&names)
+        .filter(|(variant, _name)| Some(&variant.ident) != other)
+        .map(|(variant, name)| {
+            let variant = construct(variant);
+            quote! {
+                #name => ::core::option::Option::Some(#variant),
+            }
+        })
+        .collect::<TokenStream>();
+
+    let name_fn = container.name.as_ref().map(|_| {
+        quote! {
+            pub #const_generic fn name(&self) -> &'static ::core::primitive::str {
+                match *self {
+                    #match_name
+                }
+            }
+
+            pub fn from_name(name: &::core::primitive::str) -> ::core::option::Option<Self> {
+                match name {
+                    #match_from_name
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    });
+
+    let impl_from_str = container.from_str.as_ref().map(|_| {
+        let parse_names = variants
+            .iter()
+            .zip(&variant_attrs)
+            .zip(&names)
+            .filter(|((variant, _attrs), _name)| Some(&variant.ident) != other)
+            .map(|((variant, attrs), name)| {
+                let variant = construct(variant);
+                let aliases = &attrs.aliases;
+                quote! {
+                    if s.eq_ignore_ascii_case(#name) #(|| s.eq_ignore_ascii_case(#aliases))* {
+                        return ::core::result::Result::Ok(#variant);
+                    }
+                }
+            })
+            .collect::<TokenStream>();
+        quote! {
+            impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+                type Err = #krate::ParseEnumError;
+
+                fn from_str(s: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                    #parse_names
+                    let (digits, radix) = #krate::__private::split_radix(s);
+                    if let ::core::result::Result::Ok(value) = <#repr>::from_str_radix(digits, radix) {
+                        if let ::core::option::Option::Some(variant) = Self::n(value) {
+                            return ::core::result::Result::Ok(variant);
+                        }
+                    }
+                    ::core::result::Result::Err(#krate::__private::parse_enum_error(#name, &[#list_names]))
+                }
+            }
+        }
+    });
+
+    // The body of `n` is a plain match and usable in const context, unless it
+    // calls Default::default() to construct a variant. Where the signature is
+    // generic, a const companion taking the comparison type is generated
+    // instead.
+    let const_construct = if container.default_fields.is_some() && has_data {
+        None
+    } else {
+        Some(quote!(const))
+    };
+    let constness = if explicit_repr {
+        const_construct.clone()
+    } else {
+        None
+    };
+    let value = container.value.as_ref().map(|_| {
+        let value_body = if has_data {
+            quote! {
+                match self {
+                    #match_values
+                }
+            }
+        } else {
+            quote!(self as #repr)
+        };
+        quote! {
+            pub #const_construct fn value(self) -> #repr {
+                #value_body
+            }
+        }
+    });
+    let impl_from_repr_trait = container.from_repr_trait.as_ref().map(|_| {
+        quote! {
+            impl #impl_generics #krate::FromRepr for #ident #ty_generics #where_clause {
+                type Repr = #repr;
+
+                fn n(value: #repr) -> ::core::option::Option<Self> {
+                    Self::n(value)
+                }
+            }
+        }
+    });
+    let impl_into_repr = container.value.as_ref().map(|_| {
+        quote! {
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for #repr #where_clause {
+                fn from(value: #ident #ty_generics) -> Self {
+                    ::core::convert::From::from(&value)
+                }
+            }
+
+            impl #impl_generics ::core::convert::From<&#ident #ty_generics> for #repr #where_clause {
+                fn from(value: &#ident #ty_generics) -> Self {
+                    match *value {
+                        #match_values
+                    }
+                }
+            }
+        }
+    });
+    let transmute = quote! {
+        ::core::option::Option::Some(unsafe {
+            ::core::mem::transmute::<#repr, Self>(value)
+        })
+    };
+    let match_n = match (strategy, dense) {
+        // The values with a corresponding variant form one contiguous range,
+        // so the value itself is the variant after a bounds check.
+        (Strategy::Dense, Some((lo, hi))) => quote! {
+            match value {
+                #lo..=#hi => #transmute,
+                _ => ::core::option::Option::None,
+            }
+        },
+        (Strategy::BinarySearch, _) => quote! {
+            let sorted = &helper::SORTED;
+            let mut lo = 0;
+            let mut hi = sorted.len();
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if sorted[mid] < value {
+                    lo = mid + 1;
+                } else if sorted[mid] > value {
+                    hi = mid;
+                } else {
+                    return #transmute;
+                }
+            }
+            ::core::option::Option::None
+        },
+        (Strategy::Match, _) | (Strategy::Dense, None) => quote! {
+            match value {
+                #match_n
+                _ => ::core::option::Option::None,
+            }
+        },
+    };
+    let sorted = if strategy == Strategy::BinarySearch {
+        Some(quote! {
+            const SORTED: [#repr; helper::COUNT] = {
+                let discriminants = helper::DISCRIMINANTS;
+                let mut sorted = [0; helper::COUNT];
+                let mut i = 0;
+                while i < sorted.len() {
+                    let mut j = i;
+                    while j > 0 && sorted[j - 1] > discriminants[i] {
+                        sorted[j] = sorted[j - 1];
+                        j -= 1;
+                    }
+                    sorted[j] = discriminants[i];
+                    i += 1;
+                }
+                sorted
+            };
+        })
+    } else {
+        None
+    };
+    let n = match &n_const {
+        None => quote! {
+            pub #const_construct fn n(#param) -> ::core::option::Option<Self> {
+                #match_n
+            }
+        },
+        Some(n_const) => {
+            let convert = convert_or_return(quote!(::core::option::Option::None));
+            quote! {
+                pub fn n #fallible_generics (#param) -> ::core::option::Option<Self> {
+                    #convert
+                    Self::#n_const(value)
+                }
+
+                pub #const_construct fn #n_const(value: #repr) -> ::core::option::Option<Self> {
+                    #match_n
+                }
+            }
+        }
+    };
+    // Where the value is known to be a discriminant, it can be transmuted into
+    // the enum directly. With aliases, other values also need to be mapped.
+    let n_unchecked = if primitive_repr.is_none() || other.is_some() {
+        None
+    } else {
+        let debug_assert = quote! {
+            ::core::debug_assert!(
+                ::core::option::Option::is_some(&Self::n(value)),
+                "n_unchecked called with a value that is not a discriminant of {}",
+                #name,
+            );
+        };
+        let body = if transmutable {
+            quote! {
+                #debug_assert
+                unsafe { ::core::mem::transmute::<#repr, Self>(value) }
+            }
+        } else {
+            quote! {
+                match Self::n(value) {
+                    ::core::option::Option::Some(variant) => variant,
+                    ::core::option::Option::None => {
+                        #debug_assert
+                        unsafe { ::core::hint::unreachable_unchecked() }
+                    }
+                }
+            }
+        };
+        Some(quote! {
+            /// Converts a discriminant into the corresponding variant without
+            /// checking that there is one.
+            ///
+            /// # Safety
+            ///
+            /// `value` must be the discriminant of a variant, or an alias of
+            /// one. That is, `n(value)` would have returned `Some`.
+            #[allow(unused_unsafe)]
+            pub unsafe fn n_unchecked(#param) -> Self {
+                #body
+            }
+        })
+    };
+
+    let n_total = if total {
+        let match_total = match_discriminants(&|variant| variant);
+        Some(quote! {
+            pub #const_construct fn n_total(#param) -> Self {
+                match value {
+                    #match_total
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let try_n = container.try_n.as_ref().map(|_| {
+        let try_n_generics = match &fallible_generics {
+            Some(_) => quote!(<REPR: ::core::convert::TryInto<#repr> + ::core::marker::Copy>),
+            None => TokenStream::new(),
+        };
+        let try_n_error = if explicit_repr {
+            quote!(#krate::TryFromReprError<#repr>)
+        } else {
+            quote!(#krate::TryFromReprError<REPR>)
+        };
+        quote! {
+            pub fn try_n #try_n_generics (#param) -> ::core::result::Result<Self, #try_n_error> {
+                match Self::n(value) {
+                    ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                    ::core::option::Option::None => ::core::result::Result::Err(
+                        #krate::__private::try_from_repr_error(value, #name),
+                    ),
+                }
+            }
+        }
+    });
+
+    let n_or_default = default.map(|default| {
+        let default = construct(default);
+        let convert = convert_or_return(default.clone());
+        let match_n_or_default = match_discriminants(&|variant| variant);
+        quote! {
+            pub #constness fn n_or_default #fallible_generics (#param) -> Self {
+                #convert
+                match value {
+                    #match_n_or_default
+                    _ => #default,
+                }
+            }
+        }
+    });
+
+    let open_conversions = other.map(|other| {
+        let convert = lossless_generics.as_ref().map(|_| {
+            quote! {
+                let value: #repr = ::core::convert::Into::into(value);
+            }
+        });
+        let match_from_repr = match_discriminants(&|variant| variant);
+        quote! {
+            pub #constness fn from_repr #lossless_generics (#param) -> Self {
+                #convert
+                match value {
+                    #match_from_repr
+                    _ => #ident::#other(value),
+                }
+            }
+
+            pub #const_generic fn to_repr(&self) -> #repr {
+                match *self {
+                    #match_values
+                }
+            }
+        }
+    });
+
+    // A fallible conversion gets TryFrom impls only if asked for, as a crate may
+    // already implement them by hand.
+    let impl_from_repr: &[TokenStream] = if other.is_some() {
+        &from_types
+    } else if default.is_some() || total || container.try_from.is_some() {
+        &try_from_types
+    } else {
+        &[]
+    };
+    let impl_from_repr = impl_from_repr.iter().map(|ty| {
+        if default.is_some() {
+            quote! {
+                impl #impl_generics ::core::convert::From<#ty> for #ident #ty_generics #where_clause {
+                    fn from(value: #ty) -> Self {
+                        Self::n_or_default(value)
+                    }
+                }
+            }
+        } else if other.is_some() {
+            quote! {
+                impl #impl_generics ::core::convert::From<#ty> for #ident #ty_generics #where_clause {
+                    fn from(value: #ty) -> Self {
+                        Self::from_repr(value)
+                    }
+                }
+            }
+        } else if total {
+            quote! {
+                impl #impl_generics ::core::convert::From<#ty> for #ident #ty_generics #where_clause {
+                    fn from(value: #ty) -> Self {
+                        Self::n_total(value)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics ::core::convert::TryFrom<#ty> for #ident #ty_generics #where_clause {
+                    type Error = #krate::TryFromReprError<#ty>;
+
+                    fn try_from(value: #ty) -> ::core::result::Result<Self, Self::Error> {
+                        match Self::n(value) {
+                            ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                            ::core::option::Option::None => ::core::result::Result::Err(
+                                #krate::__private::try_from_repr_error(value, #name),
+                            ),
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    let (set, impl_set) = match &container.set {
+        Some((set, _span)) => {
+            let set = match set {
+                Some(set) => set.clone(),
+                None => format_ident!("{}Set", ident.unraw()),
+            };
+            let (item, impls) = derive_set(input, variants, &set, &match_name);
+            (Some(item), Some(impls))
+        }
+        None => (None, None),
+    };
+
+    Ok(quote! {
+        #set
+
+        const _: () = {
+            #[allow(non_camel_case_types)]
+            struct discriminant;
+
+            #[allow(non_upper_case_globals)]
+            impl discriminant {
+                #declare_discriminants
+            }
+
+            #[allow(non_camel_case_types)]
+            struct helper;
+
+            impl helper {
+                const DISCRIMINANTS: &'static [#repr] = &[#list_discriminants];
+
+                const COUNT: ::core::primitive::usize = helper::DISCRIMINANTS.len();
+
+                #list_variants
+
+                #order
+
+                #sorted
+
+                #check_aliases
+            }
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #public_variants
+
+                #public_count
+
+                #public_discriminants
+
+                #iter
+
+                #n
+
+                #n_unchecked
+
+                #n_total
+
+                #n_or_default
+
+                #open_conversions
+
+                #try_n
+
+                #value
+
+                #discriminant
+
+                #name_fn
+            }
+
+            #impl_from_repr_trait
+
+            #impl_into_repr
+
+            #(#impl_from_repr)*
+
+            #impl_from_str
+
+            #impl_set
+        };
+    })
+}
+
+// For an enum with data, generates a fieldless enum with the same variants and
+// discriminants, with everything else left to a derive of N on that one.
+fn derive_kind(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Token![,]>,
+    repr: Option<&attr::Repr>,
+    container: &attr::ContainerAttrs,
+    kind: &Ident,
+) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let forward = attr::forward_to_kind(&input.attrs)?;
+    let krate = match &container.krate {
+        Some(krate) => krate.clone(),
+        None => parse_quote!(::enumn),
+    };
+
+    let mut kind_variants = Vec::new();
+    let mut match_kind = Vec::new();
+    for variant in variants {
+        let var = &variant.ident;
+        let passthrough = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("enumn"));
+        let discriminant = variant
+            .discriminant
+            .as_ref()
+            .map(|(eq, expr)| quote!(#eq #expr));
+        kind_variants.push(quote! {
+            #(#passthrough)*
+            #var #discriminant
+        });
+        let fields = match &variant.fields {
+            Fields::Named(_) => Some(quote!({ .. })),
+            Fields::Unnamed(_) => Some(quote!((..))),
+            Fields::Unit => None,
+        };
+        match_kind.push(quote! {
+            #ident::#var #fields => #kind::#var,
+        });
+    }
+
+    let vis = &input.vis;
+    let repr_attr = repr.map(|repr| match repr {
+        attr::Repr::Primitive(ty, _aligned) => quote!(#[repr(#ty)]),
+        attr::Repr::C => quote!(#[repr(C)]),
+    });
+    let doc = format!("The variants of [`{}`] without their data.", ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Trait bounds on the generic parameters of a const fn require Rust 1.61.
+    let constness = if input.generics.params.is_empty() {
+        Some(quote!(const))
+    } else {
+        None
+    };
+    let discriminant = match (&container.discriminant, repr) {
+        (Some(_), Some(attr::Repr::Primitive(ty, _aligned))) => Some(quote! {
+            pub #constness fn discriminant(&self) -> ::core::primitive::#ty {
+                Self::kind(self) as ::core::primitive::#ty
+            }
+        }),
+        (Some(discriminant), Some(attr::Repr::C) | None) => {
+            let msg = "enumn: discriminant requires a primitive #[repr] on an enum with data";
+            return Err(Error::new(discriminant.span(), msg));
+        }
+        (None, _) => None,
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(
+            ::core::marker::Copy,
+            ::core::clone::Clone,
+            ::core::fmt::Debug,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+            #krate::N,
+        )]
+        #repr_attr
+        #(#forward)*
+        #vis enum #kind {
+            #(#kind_variants,)*
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            pub #constness fn kind(&self) -> #kind {
+                match *self {
+                    #(#match_kind)*
+                }
+            }
+
+            #discriminant
+        }
+    })
+}
+
+// Generates a bit set of the variants of a fieldless enum, with one bit per
+// variant in declaration order. The struct is returned separately from its
+// impls, which go inside the anonymous const alongside `discriminant` and
+// `helper`.
+fn derive_set(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Token![,]>,
+    set: &Ident,
+    match_name: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    let ident = &input.ident;
+    let vis = &input.vis;
+
+    // Sized for every variant in the source, some of which might be
+    // configured out. Beyond 128 variants, the bits are kept in u64 words.
+    let words = match variants.len() {
+        0..=128 => None,
+        n => Some((n + 63) / 64),
+    };
+    let bits = match (variants.len(), words) {
+        (_, Some(words)) => quote!([::core::primitive::u64; #words]),
+        (0..=8, None) => quote!(::core::primitive::u8),
+        (9..=16, None) => quote!(::core::primitive::u16),
+        (17..=32, None) => quote!(::core::primitive::u32),
+        (33..=64, None) => quote!(::core::primitive::u64),
+        (_, None) => quote!(::core::primitive::u128),
+    };
+    let empty = match words {
+        None => quote!(0),
+        Some(words) => quote!([0; #words]),
+    };
+    // The word that holds the bit at index `i`, and the mask selecting it.
+    let word = |bits: TokenStream| match words {
+        None => bits,
+        Some(_) => quote!(#bits[i / 64]),
+    };
+    let mask = match words {
+        None => quote!(1 << i),
+        Some(_) => quote!(1 << (i % 64)),
+    };
+    let each_word = |op: TokenStream| match words {
+        None => quote!(self.bits #op other.bits),
+        Some(words) => quote! {{
+            let mut bits = self.bits;
+            let mut i = 0;
+            while i < #words {
+                bits[i] = self.bits[i] #op other.bits[i];
+                i += 1;
+            }
+            bits
+        }},
+    };
+    let count_ones = match words {
+        None => quote!(self.bits.count_ones() as ::core::primitive::usize),
+        Some(words) => quote! {{
+            let mut len = 0;
+            let mut i = 0;
+            while i < #words {
+                len += self.bits[i].count_ones() as ::core::primitive::usize;
+                i += 1;
+            }
+            len
+        }},
+    };
+    let is_subset = match words {
+        None => quote!(bits & !#set::ALL == 0),
+        Some(words) => quote! {{
+            let mut i = 0;
+            while i < #words && bits[i] & !#set::ALL[i] == 0 {
+                i += 1;
+            }
+            i == #words
+        }},
+    };
+
+    // Each variant's index in declaration order.
+    let declare_ordinals = variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| {
+            let variant = &variant.ident;
+            quote! {
+                const #variant: ::core::primitive::usize = #i;
+            }
+        })
+        .collect::<TokenStream>();
+    let match_ordinal = variants
+        .iter()
+        .map(|variant| {
+            let variant = &variant.ident;
+            quote! {
+                #ident::#variant => ordinal::#variant,
+            }
+        })
+        .collect::<TokenStream>();
+    let match_variant = variants
+        .iter()
+        .map(|variant| {
+            let variant = &variant.ident;
+            quote! {
+                ordinal::#variant => #ident::#variant,
+            }
+        })
+        .collect::<TokenStream>();
+
+    let word_self = word(quote!(self.bits));
+    let word_set = word(quote!(set.bits));
+    let word_local = word(quote!(bits));
+    let union = each_word(quote!(|));
+    let intersection = each_word(quote!(&));
+    let doc = format!("A set of [`{}`] variants.", ident);
+
+    let item = quote! {
+        #[doc = #doc]
+        #[derive(
+            ::core::marker::Copy,
+            ::core::clone::Clone,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+        )]
+        #vis struct #set {
+            bits: #bits,
+        }
+    };
+
+    let impls = quote! {
+        #[allow(non_camel_case_types)]
+        struct ordinal;
+
+        #[allow(non_upper_case_globals)]
+        impl ordinal {
+            #declare_ordinals
+        }
+
+        impl helper {
+            const fn ordinal(variant: &#ident) -> ::core::primitive::usize {
+                match *variant {
+                    #match_ordinal
+                }
+            }
+
+            fn variant(i: ::core::primitive::usize) -> #ident {
+                match i {
+                    #match_variant
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+
+        impl #set {
+            const ALL: #bits = {
+                let mut bits = #empty;
+                let mut i = 0;
+                while i < helper::COUNT {
+                    #word_local |= #mask;
+                    i += 1;
+                }
+                bits
+            };
+
+            pub const fn new() -> Self {
+                #set { bits: #empty }
+            }
+
+            pub const fn all() -> Self {
+                #set { bits: #set::ALL }
+            }
+
+            pub const fn with(self, variant: #ident) -> Self {
+                let i = helper::ordinal(&variant);
+                let mut bits = self.bits;
+                #word_local |= #mask;
+                #set { bits }
+            }
+
+            pub const fn from_bits(bits: #bits) -> ::core::option::Option<Self> {
+                if #is_subset {
+                    ::core::option::Option::Some(#set { bits })
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            pub const fn bits(&self) -> #bits {
+                self.bits
+            }
+
+            pub const fn len(&self) -> ::core::primitive::usize {
+                #count_ones
+            }
+
+            pub const fn is_empty(&self) -> ::core::primitive::bool {
+                self.len() == 0
+            }
+
+            pub const fn contains(&self, variant: #ident) -> ::core::primitive::bool {
+                let i = helper::ordinal(&variant);
+                #word_self & #mask != 0
+            }
+
+            pub fn insert(&mut self, variant: #ident) -> ::core::primitive::bool {
+                let i = helper::ordinal(&variant);
+                let inserted = #word_self & #mask == 0;
+                #word_self |= #mask;
+                inserted
+            }
+
+            pub fn remove(&mut self, variant: #ident) -> ::core::primitive::bool {
+                let i = helper::ordinal(&variant);
+                let removed = #word_self & #mask != 0;
+                #word_self &= !(#mask);
+                removed
+            }
+
+            pub const fn union(self, other: Self) -> Self {
+                #set { bits: #union }
+            }
+
+            pub const fn intersection(self, other: Self) -> Self {
+                #set { bits: #intersection }
+            }
+
+            pub fn iter(&self) -> impl ::core::iter::Iterator<Item = #ident> {
+                let set = *self;
+                ::core::iter::Iterator::map(
+                    ::core::iter::Iterator::filter(0..helper::COUNT, move |&i| {
+                        #word_set & #mask != 0
+                    }),
+                    helper::variant,
+                )
+            }
+        }
+
+        impl ::core::default::Default for #set {
+            fn default() -> Self {
+                #set::new()
+            }
+        }
+
+        impl ::core::fmt::Debug for #set {
+            fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                formatter.write_str("{")?;
+                for (i, variant) in ::core::iter::Iterator::enumerate(self.iter()) {
+                    if i > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    formatter.write_str(match variant {
+                        #match_name
+                    })?;
+                }
+                formatter.write_str("}")
+            }
+        }
+
+        impl ::core::iter::FromIterator<#ident> for #set {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: ::core::iter::IntoIterator<Item = #ident>,
+            {
+                let mut set = #set::new();
+                for variant in iter {
+                    set.insert(variant);
+                }
+                set
+            }
+        }
+    };
+
+    (item, impls)
+}
+
+// Enums with fewer variants than this are left to LLVM, which turns the match
+// into a range check or a search tree by itself. The benefit of bypassing the
+// match is in the compile time and code size of enums with hundreds of
+// variants, as measured by the compile bench in bench/.
+const DENSE_THRESHOLD: usize = 128;
+const BINARY_SEARCH_THRESHOLD: usize = 128;
+
+// How many missing ranges to list in the error for #[enumn(exhaustive)].
+const MISSING_LIMIT: usize = 16;
+
+// The range covered by the discriminants, if every discriminant is known and
+// together they cover the range without gaps.
+fn dense(literals: &[Option<i128>]) -> Option<(Int, Int)> {
+    let mut discriminants = literals.iter().copied().collect::<Option<Vec<i128>>>()?;
+    discriminants.sort_unstable();
+    let lo = *discriminants.first()?;
+    let hi = *discriminants.last()?;
+    if hi.checked_sub(lo)? != discriminants.len() as i128 - 1 {
+        return None;
+    }
+    let span = Span::call_site();
+    Some((Int { value: lo, span }, Int { value: hi, span }))
+}
+
+fn combine(errors: Vec<Error>) -> Error {
+    let mut errors = errors.into_iter();
+    let mut combined = errors.next().unwrap();
+    for error in errors {
+        combined.combine(error);
+    }
+    combined
+}
@@ -0,0 +1,25 @@
+#![allow(
+    clippy::enum_glob_use,
+    clippy::enum_variant_names,
+    clippy::missing_panics_doc,
+    clippy::single_match_else,
+    clippy::too_many_lines
+)]
+
+extern crate proc_macro;
+
+mod attr;
+mod case;
+mod expand;
+mod literal;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Error};
+
+#[proc_macro_derive(N, attributes(enumn))]
+pub fn derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand::derive(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,153 @@
+use proc_macro2::{Ident, Literal, Punct, Spacing, Span, TokenStream};
+use quote::{quote, ToTokens, TokenStreamExt as _};
+use syn::parse::{ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::{Error, Expr, ExprLit, ExprUnary, Lit, LitInt, Token, UnOp, Variant};
+
+// An integer literal, possibly negated, as written in an enumn attribute or
+// an explicit discriminant.
+#[derive(Copy, Clone)]
+pub struct Int {
+    pub value: i128,
+    pub span: Span,
+}
+
+impl Int {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let neg: Option<Token![-]> = input.parse()?;
+        let lit: LitInt = input.parse()?;
+        let span = lit.span();
+        let value = match lit.base10_parse::<i128>() {
+            Ok(value) if neg.is_some() => -value,
+            Ok(value) => value,
+            Err(_) => return Err(Error::new(span, "enumn: integer literal out of range")),
+        };
+        Ok(Int { value, span })
+    }
+}
+
+impl ToTokens for Int {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.value < 0 {
+            tokens.append(Punct::new('-', Spacing::Alone));
+        }
+        let mut literal = Literal::u128_unsuffixed(self.value.unsigned_abs());
+        literal.set_span(self.span);
+        tokens.append(literal);
+    }
+}
+
+// A value from #[enumn(alias = 0x11)] or range from #[enumn(alias = 0x20..=0x2F)].
+pub struct Alias {
+    pub lo: Int,
+    pub hi: Int,
+}
+
+impl Alias {
+    pub fn parse(input: ParseStream) -> Result<Self> {
+        let lo = Int::parse(input)?;
+        if input.parse::<Option<Token![..=]>>()?.is_none() {
+            return Ok(Alias { lo, hi: lo });
+        }
+        let hi = Int::parse(input)?;
+        if lo.value > hi.value {
+            return Err(Error::new(hi.span, "enumn: alias range is empty"));
+        }
+        Ok(Alias { lo, hi })
+    }
+
+    pub fn contains(&self, value: i128) -> bool {
+        self.lo.value <= value && value <= self.hi.value
+    }
+
+    pub fn overlaps(&self, other: &Alias) -> bool {
+        self.lo.value <= other.hi.value && other.lo.value <= self.hi.value
+    }
+}
+
+impl ToTokens for Alias {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let lo = &self.lo;
+        let hi = &self.hi;
+        if lo.value == hi.value {
+            lo.to_tokens(tokens);
+        } else {
+            tokens.extend(quote!(#lo..=#hi));
+        }
+    }
+}
+
+// The discriminant of each variant, where it can be determined from integer
+// literals without the help of the compiler. A variant whose discriminant is
+// an arbitrary expression, or follows one, is None.
+pub fn discriminants(variants: &Punctuated<Variant, Token![,]>) -> Vec<Option<i128>> {
+    let mut discriminants = Vec::new();
+    let mut next = Some(0);
+    for variant in variants {
+        let discriminant = match &variant.discriminant {
+            Some((_eq, expr)) => int(expr),
+            None => next,
+        };
+        discriminants.push(discriminant);
+        next = discriminant.and_then(|discriminant| discriminant.checked_add(1));
+    }
+    discriminants
+}
+
+// The values of a primitive integer type whose width does not depend on the
+// target.
+pub fn domain(ty: &Ident) -> Option<(i128, i128)> {
+    Some(match ty.to_string().as_str() {
+        "u8" => (0, u8::MAX.into()),
+        "u16" => (0, u16::MAX.into()),
+        "u32" => (0, u32::MAX.into()),
+        "u64" => (0, u64::MAX.into()),
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        // The 128-bit types exceed what an enumn attribute can express.
+        _ => return None,
+    })
+}
+
+// The subranges of lo..=hi that are not covered by any of the given ranges.
+pub fn uncovered(lo: i128, hi: i128, mut covered: Vec<(i128, i128)>) -> Vec<(i128, i128)> {
+    covered.sort_unstable();
+    let mut missing = Vec::new();
+    let mut next = Some(lo);
+    for (start, end) in covered {
+        let first = match next {
+            Some(first) if first <= hi => first,
+            _ => break,
+        };
+        if start > first {
+            missing.push((first, (start - 1).min(hi)));
+        }
+        if end >= first {
+            next = end.checked_add(1);
+        }
+    }
+    if let Some(first) = next {
+        if first <= hi {
+            missing.push((first, hi));
+        }
+    }
+    missing
+}
+
+fn int(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => int(expr).map(|value| -value),
+        Expr::Group(group) => int(&group.expr),
+        Expr::Paren(paren) => int(&paren.expr),
+        _ => None,
+    }
+}
@@ -0,0 +1,122 @@
+use core::fmt::{self, Debug, Display};
+
+/// The error type returned when a checked integer to enum conversion fails.
+///
+/// This is the error type of the `try_n` function generated by
+/// `#[enumn(try_n)]` and of every `TryFrom` impl generated by
+/// `#[enumn(try_from)]`.
+/// It holds on to the integer that did not correspond to any variant, as well
+/// as the name of the enum, so that it can be reported without further context
+/// from the call site.
+///
+/// ```
+/// #[derive(Debug, enumn::N)]
+/// #[repr(u8)]
+/// #[enumn(try_n)]
+/// enum Opcode {
+///     Nop = 0x00,
+///     Halt = 0xFF,
+/// }
+///
+/// let err = Opcode::try_n(0x42).unwrap_err();
+/// assert_eq!(err.value(), 0x42);
+/// assert_eq!(err.enum_name(), "Opcode");
+/// assert_eq!(err.to_string(), "66 is not a valid discriminant of enum Opcode");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TryFromReprError<Repr> {
+    value: Repr,
+    enum_name: &'static str,
+}
+
+impl<Repr> TryFromReprError<Repr> {
+    pub(crate) fn new(value: Repr, enum_name: &'static str) -> Self {
+        TryFromReprError { value, enum_name }
+    }
+
+    /// The integer that was rejected by the conversion.
+    pub fn value(&self) -> Repr
+    where
+        Repr: Copy,
+    {
+        self.value
+    }
+
+    /// The name of the enum that the conversion targeted.
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+}
+
+impl<Repr: Display> Display for TryFromReprError<Repr> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} is not a valid discriminant of enum {}",
+            self.value, self.enum_name,
+        )
+    }
+}
+
+#[cfg(not(no_core_error))]
+impl<Repr: Debug + Display> core::error::Error for TryFromReprError<Repr> {}
+
+/// The error type returned when parsing a string into an enum fails.
+///
+/// This is the `FromStr::Err` of enums annotated with `#[enumn(from_str)]`.
+/// It carries the name of the enum and the variant names that would have been
+/// accepted.
+///
+/// ```
+/// #[derive(Debug, enumn::N)]
+/// #[enumn(from_str)]
+/// enum Level {
+///     Low,
+///     High,
+/// }
+///
+/// let err = "medium".parse::<Level>().unwrap_err();
+/// assert_eq!(err.choices(), ["Low", "High"]);
+/// assert_eq!(
+///     err.to_string(),
+///     "expected one of `Low`, `High`, or a discriminant of enum Level",
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseEnumError {
+    enum_name: &'static str,
+    choices: &'static [&'static str],
+}
+
+impl ParseEnumError {
+    pub(crate) fn new(enum_name: &'static str, choices: &'static [&'static str]) -> Self {
+        ParseEnumError { enum_name, choices }
+    }
+
+    /// The name of the enum that the string was parsed into.
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+
+    /// The variant names accepted by the parser, ignoring case and aliases.
+    pub fn choices(&self) -> &'static [&'static str] {
+        self.choices
+    }
+}
+
+impl Display for ParseEnumError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("expected ")?;
+        if !self.choices.is_empty() {
+            formatter.write_str("one of ")?;
+            for choice in self.choices {
+                write!(formatter, "`{}`, ", choice)?;
+            }
+            formatter.write_str("or ")?;
+        }
+        write!(formatter, "a discriminant of enum {}", self.enum_name)
+    }
+}
+
+#[cfg(not(no_core_error))]
+impl core::error::Error for ParseEnumError {}
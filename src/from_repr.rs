@@ -0,0 +1,36 @@
+/// Conversion from an integer, implemented by `#[enumn(from_repr_trait)]`.
+///
+/// Every enum with the derive has an inherent `n` function. This trait exposes
+/// the same conversion for code that is generic over the enum, such as
+/// decoders and registries written once for many enums.
+///
+/// `Repr` is the type accepted by `n`: the enum's `repr` if one is specified,
+/// otherwise the type that the derive compares discriminants in, which is
+/// `i64` unless widened with `#[enumn(repr_type = i128)]`.
+///
+/// ```
+/// use enumn::FromRepr;
+///
+/// #[derive(PartialEq, Debug, enumn::N)]
+/// #[repr(u8)]
+/// #[enumn(from_repr_trait)]
+/// enum Opcode {
+///     Nop = 0x00,
+///     Halt = 0xFF,
+/// }
+///
+/// fn decode<E: FromRepr<Repr = u8>>(bytes: &[u8]) -> Option<Vec<E>> {
+///     bytes.iter().map(|&byte| E::n(byte)).collect()
+/// }
+///
+/// assert_eq!(decode(&[0xFF, 0x00]), Some(vec![Opcode::Halt, Opcode::Nop]));
+/// assert_eq!(decode::<Opcode>(&[0x42]), None);
+/// ```
+pub trait FromRepr: Sized {
+    /// The integer type that is converted from.
+    type Repr;
+
+    /// Returns the variant corresponding to `value`, or `None` if there is
+    /// none.
+    fn n(value: Self::Repr) -> Option<Self>;
+}
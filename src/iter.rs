@@ -0,0 +1,77 @@
+use core::iter::FusedIterator;
+use core::slice;
+
+/// An iterator over the variants of an enum.
+///
+/// This is the type returned by the `iter` function generated by
+/// `#[enumn(iter)]`. Variants are produced in order of their discriminant, or
+/// in declaration order if the enum is additionally annotated with
+/// `#[enumn(iter_order = "declaration")]`.
+///
+/// ```
+/// #[derive(PartialEq, Debug, enumn::N)]
+/// #[enumn(iter)]
+/// enum Direction {
+///     North = 0,
+///     South = 180,
+///     East = 90,
+///     West = 270,
+/// }
+///
+/// let mut iter = Direction::iter();
+/// assert_eq!(iter.len(), 4);
+/// assert_eq!(iter.next(), Some(Direction::North));
+/// assert_eq!(iter.next(), Some(Direction::East));
+/// assert_eq!(iter.next_back(), Some(Direction::West));
+/// assert_eq!(iter.next(), Some(Direction::South));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub struct Iter<E: 'static> {
+    variants: &'static [E],
+    order: slice::Iter<'static, usize>,
+    copy: fn(&E) -> E,
+}
+
+impl<E> Iter<E> {
+    pub(crate) fn new(variants: &'static [E], order: &'static [usize], copy: fn(&E) -> E) -> Self {
+        Iter {
+            variants,
+            order: order.iter(),
+            copy,
+        }
+    }
+}
+
+impl<E> Iterator for Iter<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &index = self.order.next()?;
+        Some((self.copy)(&self.variants[index]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<E> DoubleEndedIterator for Iter<E> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let &index = self.order.next_back()?;
+        Some((self.copy)(&self.variants[index]))
+    }
+}
+
+impl<E> ExactSizeIterator for Iter<E> {}
+
+impl<E> FusedIterator for Iter<E> {}
+
+impl<E> Clone for Iter<E> {
+    fn clone(&self) -> Self {
+        Iter {
+            variants: self.variants,
+            order: self.order.clone(),
+            copy: self.copy,
+        }
+    }
+}
@@ -76,6 +76,10 @@
 //! }
 //! ```
 //!
+//! The integer type may be combined with other representation hints, as in
+//! `#[repr(C, u8)]`. An enum that is only `#[repr(C)]` has the size of the
+//! platform's C `int`, and `n` accordingly takes a `core::ffi::c_int`.
+//!
 //! On the other hand if no `repr` is specified then we get a signature that is
 //! generic over a variety of possible types.
 //!
@@ -83,13 +87,19 @@
 //! # enum E {}
 //! #
 //! impl E {
-//!     pub fn n<REPR: Into<i64>>(value: REPR) -> Option<Self> {
+//!     pub fn n<REPR: TryInto<i64>>(value: REPR) -> Option<Self> {
 //!         /* ... */
 //!         # unimplemented!()
 //!     }
 //! }
 //! ```
 //!
+//! Any primitive integer type is accepted. Values that do not fit in `i64`,
+//! such as a large `u64`, return `None`. The discriminants of an enum without a
+//! `repr` are `isize`, so `i64` holds all of them; the comparison can be widened
+//! to `i128` with `#[enumn(repr_type = i128)]`, which also becomes the type
+//! returned by the reverse conversion.
+//!
 //! # Discriminants
 //!
 //! The conversion respects explictly specified enum discriminants. Consider
@@ -104,104 +114,547 @@
 //! ```
 //!
 //! Here `Letter::n(65)` would return `Some(Letter::A)`.
+//!
+//! Variants disabled by `#[cfg]` are removed by the compiler before the
+//! derive sees the enum, so they take no part in the conversion and the
+//! variants after them are numbered as if they were never written.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! enum Feature {
+//!     Base,
+//!     #[cfg(any())]
+//!     Disabled,
+//!     Extra,
+//! }
+//!
+//! assert_eq!(Feature::n(1), Some(Feature::Extra));
+//! ```
+//!
+//! # Alias values
+//!
+//! Additional integers that should convert to a variant can be listed with
+//! `#[enumn(alias = ...)]`, which takes an integer literal or an inclusive
+//! range and may be repeated. Aliases that overlap one another or the
+//! discriminant of any variant are a compile error.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(value)]
+//! enum State {
+//!     #[enumn(alias = 0x11)]
+//!     Idle = 0x10,
+//!     #[enumn(alias = 0x20..=0x2F)]
+//!     Busy = 0x30,
+//! }
+//!
+//! assert_eq!(State::n(0x11), Some(State::Idle));
+//! assert_eq!(State::n(0x2A), Some(State::Busy));
+//! assert_eq!(u8::from(State::Busy), 0x30);
+//! ```
+//!
+//! # Const
+//!
+//! With a `repr`, `n` is a `const fn` and can be used to decode values while
+//! building constants, statics, and lookup tables. Without a `repr`, the
+//! generic `n` is accompanied by a `const fn n_i64(value: i64)`, or `n_i128`
+//! for an enum with `#[enumn(repr_type = i128)]`.
+//!
+//! ```
+//! #[derive(enumn::N)]
+//! #[repr(u8)]
+//! enum Letter {
+//!     A = 65,
+//!     B = 66,
+//! }
+//!
+//! static TABLE: [Option<Letter>; 3] = [Letter::n(64), Letter::n(65), Letter::n(66)];
+//! ```
+//!
+//! # Strategy
+//!
+//! By default `n` is a `match` on the discriminants, which the compiler is
+//! good at optimizing. For enums with a primitive `repr` and 128 or more
+//! variants, the derive instead checks that the value is in range if the
+//! discriminants are contiguous, or otherwise does a binary search in a sorted
+//! table of the discriminants, keeping compile time and code size in check.
+//! The choice can be made explicitly with `#[enumn(strategy = "match")]`,
+//! `"dense"`, or `"binary_search"`.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u32)]
+//! #[enumn(strategy = "binary_search")]
+//! enum Status {
+//!     Continue = 100,
+//!     Ok = 200,
+//!     NotFound = 404,
+//! }
+//!
+//! assert_eq!(Status::n(404), Some(Status::NotFound));
+//! ```
+//!
+//! # Listing variants
+//!
+//! With `#[enumn(variants)]` on the enum, the derive generates associated
+//! constants `VARIANTS`, `COUNT`, and `DISCRIMINANTS` which list the unit
+//! variants and their discriminants in declaration order. A constant is left
+//! out if the enum has a variant of the same name.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[enumn(variants)]
+//! enum Letter {
+//!     A = 65,
+//!     B = 66,
+//! }
+//!
+//! assert_eq!(Letter::VARIANTS, [Letter::A, Letter::B]);
+//! assert_eq!(Letter::COUNT, 2);
+//! assert_eq!(Letter::DISCRIMINANTS, [65, 66]);
+//! ```
+//!
+//! For iterating, `#[enumn(iter)]` generates `iter()` returning an [`Iter`]
+//! which yields the variants by value in order of their discriminant, or in
+//! declaration order if the enum is also annotated with
+//! `#[enumn(iter_order = "declaration")]`.
+//!
+//! ```
+//! # #[derive(PartialEq, Debug, enumn::N)]
+//! # #[enumn(iter)]
+//! # enum Letter {
+//! #     A = 65,
+//! #     B = 66,
+//! # }
+//! #
+//! for letter in Letter::iter() {
+//!     println!("{:?}", letter);
+//! }
+//! ```
+//!
+//! # Variant names
+//!
+//! With `#[enumn(name)]` on the enum, the derive generates
+//! `const fn name(&self) -> &'static str` returning the name of the variant,
+//! and `from_name` which parses one back. Names can be adjusted per variant
+//! with `#[enumn(rename = "...")]`, or for all variants with
+//! `#[enumn(rename_all = "...")]` using one of the conventions `"lowercase"`,
+//! `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`,
+//! `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, or `"SCREAMING-KEBAB-CASE"`.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[enumn(name, rename_all = "snake_case")]
+//! enum Mode {
+//!     LowLatency,
+//!     #[enumn(rename = "bulk")]
+//!     HighThroughput,
+//! }
+//!
+//! assert_eq!(Mode::LowLatency.name(), "low_latency");
+//! assert_eq!(Mode::from_name("bulk"), Some(Mode::HighThroughput));
+//! ```
+//!
+//! # Parsing strings
+//!
+//! With `#[enumn(from_str)]` on the enum, the derive implements [`FromStr`]
+//! for parsing configuration written by humans. Variant names, including any
+//! renames, are matched ignoring ASCII case, as are any number of additional
+//! `#[enumn(alias_name = "...")]` strings per variant. A string of decimal or
+//! `0x`-prefixed hexadecimal digits is parsed as a discriminant and converted
+//! with `n`. Anything else produces a [`ParseEnumError`] listing the accepted
+//! names.
+//!
+//! [`FromStr`]: core::str::FromStr
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(from_str, rename_all = "lowercase")]
+//! enum Protocol {
+//!     Tcp = 6,
+//!     #[enumn(alias_name = "datagram")]
+//!     Udp = 17,
+//! }
+//!
+//! assert_eq!("UDP".parse(), Ok(Protocol::Udp));
+//! assert_eq!("datagram".parse(), Ok(Protocol::Udp));
+//! assert_eq!("0x11".parse(), Ok(Protocol::Udp));
+//!
+//! let err = "icmp".parse::<Protocol>().unwrap_err();
+//! assert_eq!(err.choices(), ["tcp", "udp"]);
+//! ```
+//!
+//! # Unchecked conversion
+//!
+//! For enums with a primitive `repr`, the derive also generates an
+//! `unsafe fn n_unchecked(value: Repr) -> Self` for hot paths where the value
+//! has already been validated. It skips the check that `n` performs, and
+//! unless the enum has aliases it amounts to a reinterpretation of the integer.
+//!
+//! The caller must guarantee that `value` is the discriminant of a variant, or
+//! one of its aliases; in other words that `n(value)` would return `Some`.
+//! Calling it with any other value is undefined behavior. In debug builds this
+//! is checked by an assertion.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! enum Opcode {
+//!     Nop = 0x00,
+//!     Halt = 0xFF,
+//! }
+//!
+//! let byte = 0xFF;
+//! if Opcode::n(byte).is_some() {
+//!     // SAFETY: checked above.
+//!     let opcode = unsafe { Opcode::n_unchecked(byte) };
+//!     assert_eq!(opcode, Opcode::Halt);
+//! }
+//! ```
+//!
+//! # Total conversion
+//!
+//! If the discriminants and aliases of an enum with a fixed width integer
+//! `repr` cover every value of that integer type, `n` cannot fail. The derive
+//! detects this and additionally generates a `const fn n_total(value: Repr) ->
+//! Self` and an infallible `From<Repr>` impl in place of `TryFrom`. To rule out
+//! accidentally missing a value, `#[enumn(exhaustive)]` makes it a compile
+//! error if the enum does not cover every value, listing the ones missing.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(exhaustive)]
+//! enum Level {
+//!     #[enumn(alias = 1..=0x7F)]
+//!     Low = 0,
+//!     #[enumn(alias = 0x81..=0xFF)]
+//!     High = 0x80,
+//! }
+//!
+//! assert_eq!(Level::n_total(0x33), Level::Low);
+//! assert_eq!(Level::from(0xC0), Level::High);
+//! ```
+//!
+//! # Generic code
+//!
+//! With `#[enumn(from_repr_trait)]`, the derive also implements the
+//! [`FromRepr`] trait, whose associated `Repr` type is the type accepted by
+//! `n`, so that code can be written once for all enums that have it.
+//!
+//! ```
+//! use enumn::FromRepr;
+//!
+//! fn decode<E: FromRepr<Repr = u8>>(byte: u8) -> Result<E, String> {
+//!     E::n(byte).ok_or_else(|| format!("unexpected byte {:#04x}", byte))
+//! }
+//! ```
+//!
+//! # Enums with data
+//!
+//! An enum whose variants carry data cannot be produced from an integer. For
+//! decoding the tag of such an enum before its payload, `#[enumn(kind = Name)]`
+//! generates a fieldless enum `Name` with the same variants, discriminants, and
+//! `repr`, on which `N` is derived, along with a `kind(&self)` method returning
+//! the variant without its data. Other `enumn` attributes of the enum and its
+//! variants apply to the generated enum. Explicit discriminants on an enum with
+//! data require Rust 1.66 or newer.
+//!
+//! ```
+//! # #[rustversion::since(1.66)]
+//! # fn main() {
+//! #[derive(enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(kind = MsgKind)]
+//! enum Msg {
+//!     Ping = 1,
+//!     Data(Vec<u8>),
+//!     Close { code: u16 },
+//! }
+//!
+//! assert_eq!(MsgKind::n(2), Some(MsgKind::Data));
+//! assert_eq!(Msg::Close { code: 1000 }.kind(), MsgKind::Close);
+//! # }
+//! #
+//! # #[rustversion::before(1.66)]
+//! # fn main() {}
+//! ```
+//!
+//! Alternatively, `#[enumn(default_fields)]` derives `N` on the enum with data
+//! itself. A variant with fields is then produced with every field set to
+//! `Default::default()`. Because that is not a const operation, `n` is not a
+//! `const fn` on such an enum, and neither `VARIANTS` nor `iter` is available.
+//!
+//! ```
+//! # #[rustversion::since(1.66)]
+//! # fn main() {
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(default_fields, value)]
+//! enum Msg {
+//!     Ping = 1,
+//!     Data(Vec<u8>),
+//!     Close { code: u16 },
+//! }
+//!
+//! assert_eq!(Msg::n(3), Some(Msg::Close { code: 0 }));
+//! assert_eq!(u8::from(&Msg::Data(vec![0xFF])), 2);
+//! # }
+//! #
+//! # #[rustversion::before(1.66)]
+//! # fn main() {}
+//! ```
+//!
+//! With a primitive `repr`, `#[enumn(discriminant)]` generates a
+//! `discriminant(&self)` method on an enum with data through either attribute,
+//! which reads the discriminant without the pointer cast that the language
+//! otherwise requires. The same attribute generates the method on fieldless
+//! enums, borrowing where `value` takes the enum by value.
+//!
+//! ```
+//! # #[rustversion::since(1.66)]
+//! # fn main() {
+//! #[derive(enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(kind = MsgKind, discriminant)]
+//! enum Msg {
+//!     Ping = 1,
+//!     Data(Vec<u8>),
+//!     Close { code: u16 },
+//! }
+//!
+//! assert_eq!(Msg::Data(vec![0xFF]).discriminant(), 2);
+//! assert_eq!(Msg::Close { code: 1000 }.discriminant(), 3);
+//! # }
+//! #
+//! # #[rustversion::before(1.66)]
+//! # fn main() {}
+//! ```
+//!
+//! # Sets of variants
+//!
+//! `#[enumn(set)]` generates a set type named after the enum with a `Set`
+//! suffix, or `#[enumn(set = Name)]` for a different name. It holds one bit per
+//! variant in declaration order, in the smallest unsigned integer type with
+//! enough bits, or an array of `u64` for enums with more than 128 variants.
+//! Besides `insert`, `remove`, `contains`, `iter`, `union`, and `intersection`,
+//! the const functions `new`, `all`, and `with` build sets at compile time, and
+//! `from_bits` accepts a raw bit mask only if every set bit belongs to a
+//! variant.
+//!
+//! ```
+//! #[derive(Copy, Clone, PartialEq, Debug, enumn::N)]
+//! #[enumn(set)]
+//! enum Feature {
+//!     Compression,
+//!     Encryption,
+//!     Multiplexing,
+//! }
+//!
+//! const DEFAULT: FeatureSet = FeatureSet::new().with(Feature::Encryption);
+//!
+//! let mut features = DEFAULT;
+//! features.insert(Feature::Multiplexing);
+//! assert_eq!(features.bits(), 0b110);
+//! assert_eq!(format!("{:?}", features), "{Encryption, Multiplexing}");
+//! assert_eq!(FeatureSet::from_bits(0b1000), None);
+//! ```
+//!
+//! # Fallback variant
+//!
+//! For a total conversion, one unit variant may be marked
+//! `#[enumn(default)]`. The derive then additionally generates an infallible
+//! `n_or_default` function which maps every value without a corresponding
+//! variant to the marked one, and implements `From` instead of `TryFrom`.
+//!
+//! ```
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! enum Command {
+//!     Ping = 1,
+//!     Pong = 2,
+//!     #[enumn(default)]
+//!     Unknown = 0xFF,
+//! }
+//!
+//! assert_eq!(Command::n_or_default(2), Command::Pong);
+//! assert_eq!(Command::n_or_default(7), Command::Unknown);
+//! assert_eq!(Command::from(7), Command::Unknown);
+//! ```
+//!
+//! # Open enums
+//!
+//! Where unknown values need to survive a round trip unchanged, one tuple
+//! variant holding the repr integer may be marked `#[enumn(other)]`. The derive
+//! then generates a total `from_repr` which maps values without a corresponding
+//! unit variant into the marked variant, and a `to_repr` which gives them back.
+//! Because the marked variant has a field, explicit discriminants on the other
+//! variants require Rust 1.66 or newer.
+//!
+//! ```
+//! # #[rustversion::since(1.66)]
+//! # fn main() {
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! enum Protocol {
+//!     Tcp = 6,
+//!     Udp = 17,
+//!     #[enumn(other)]
+//!     Other(u8),
+//! }
+//!
+//! assert_eq!(Protocol::from_repr(17), Protocol::Udp);
+//! assert_eq!(Protocol::from_repr(41), Protocol::Other(41));
+//! assert_eq!(Protocol::Other(41).to_repr(), 41);
+//! # }
+//! #
+//! # #[rustversion::before(1.66)]
+//! # fn main() {}
+//! ```
+//!
+//! # Reverse conversion
+//!
+//! Going the other direction, `#[enumn(value)]` on the enum generates a `const
+//! fn value(self)` and implements `From<E>` and `From<&E>` for the same integer
+//! type that `n` accepts: the `repr` if one is specified, otherwise `i64`.
+//! Unlike an `as` cast, these keep compiling only for as long as the integer
+//! type agrees with the enum's `repr`.
+//!
+//! ```
+//! #[derive(enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(value)]
+//! enum Letter {
+//!     A = 65,
+//!     B = 66,
+//! }
+//!
+//! assert_eq!(Letter::B.value(), 66);
+//! assert_eq!(u8::from(&Letter::A), 65);
+//! ```
+//!
+//! # Errors
+//!
+//! When the rejected integer needs to be reported, `#[enumn(try_n)]` on the
+//! enum generates `try_n`, a drop-in alternative to `n` that returns a
+//! [`TryFromReprError`] instead of `None`. The error records the offending
+//! value and the name of the enum, implements `Display`, and implements
+//! `core::error::Error` on Rust 1.81 and newer.
+//!
+//! ```
+//! #[derive(Debug, enumn::N)]
+//! #[enumn(try_n)]
+//! enum Status {
+//!     LegendaryTriumph,
+//! }
+//!
+//! let err = Status::try_n(9).unwrap_err();
+//! assert_eq!(err.to_string(), "9 is not a valid discriminant of enum Status");
+//! ```
+//!
+//! # Standard conversion traits
+//!
+//! With `#[enumn(try_from)]`, the derive additionally implements [`TryFrom`]
+//! so that enums can be passed to code written against the standard
+//! conversion traits. If a `repr` is specified, `TryFrom` is implemented for
+//! that type. Otherwise it is implemented for every primitive integer type.
+//!
+//! ```
+//! use core::convert::TryFrom;
+//!
+//! #[derive(PartialEq, Debug, enumn::N)]
+//! #[repr(u8)]
+//! #[enumn(try_from)]
+//! enum Letter {
+//!     A = 65,
+//!     B = 66,
+//! }
+//!
+//! assert_eq!(Letter::try_from(66), Ok(Letter::B));
+//!
+//! let err = Letter::try_from(67).unwrap_err();
+//! assert_eq!(err.value(), 67);
+//! ```
+//!
+//! # Re-exporting
+//!
+//! Some of the generated code, such as that of `#[enumn(try_n)]`, `iter`, or a
+//! `#[repr(C)]` enum, names items of this crate by the path `::enumn`. A crate
+//! that re-exports the derive to users who do not depend on `enumn` directly,
+//! or that renames the dependency, can point the derive at the crate with
+//! `#[enumn(crate = path)]`.
+//!
+//! ```
+//! # extern crate enumn as _;
+//! #
+//! mod facade {
+//!     pub use enumn;
+//! }
+//!
+//! #[derive(facade::enumn::N)]
+//! #[enumn(crate = facade::enumn, iter)]
+//! enum Letter {
+//!     A,
+//!     B,
+//! }
+//!
+//! assert_eq!(Letter::iter().len(), 2);
+//! ```
 
 #![doc(html_root_url = "https://docs.rs/enumn/0.1.13")]
-#![allow(
-    clippy::missing_panics_doc,
-    clippy::needless_doctest_main,
-    clippy::single_match_else
-)]
+#![no_std]
+#![allow(clippy::must_use_candidate, clippy::needless_doctest_main)]
 
-extern crate proc_macro;
+mod error;
+mod from_repr;
+mod iter;
 
-use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident};
+pub use crate::error::{ParseEnumError, TryFromReprError};
+pub use crate::from_repr::FromRepr;
+pub use crate::iter::Iter;
+pub use enumn_impl::N;
 
-#[proc_macro_derive(N)]
-pub fn derive(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+// Not public API. Used by generated code.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{Iter, ParseEnumError, TryFromReprError};
 
-    let variants = match input.data {
-        Data::Enum(data) => data.variants,
-        Data::Struct(_) | Data::Union(_) => panic!("input must be an enum"),
-    };
+    #[cfg(not(no_core_ffi_c_int))]
+    #[allow(clippy::incompatible_msrv, non_camel_case_types)]
+    pub type c_int = core::ffi::c_int;
 
-    for variant in &variants {
-        match variant.fields {
-            Fields::Unit => {}
-            Fields::Named(_) | Fields::Unnamed(_) => {
-                let span = variant.ident.span();
-                let err = Error::new(span, "enumn: variant with data is not supported");
-                return err.to_compile_error().into();
-            }
-        }
-    }
+    // Before Rust 1.64 the C int type was only nameable through std. It is 32
+    // bits wide on every target other than a handful of 16-bit tier 3 ones.
+    #[cfg(no_core_ffi_c_int)]
+    #[allow(non_camel_case_types)]
+    pub type c_int = i32;
 
-    // Parse repr attribute like #[repr(u16)].
-    let mut repr = None;
-    for attr in input.attrs {
-        if attr.path().is_ident("repr") {
-            if let Ok(name) = attr.parse_args::<Ident>() {
-                match name.to_string().as_str() {
-                    "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32"
-                    | "i64" | "i128" | "isize" => {
-                        repr = Some(quote!(#name));
-                    }
-                    _ => {}
-                }
-            }
-        }
+    pub fn try_from_repr_error<Repr>(
+        value: Repr,
+        enum_name: &'static str,
+    ) -> TryFromReprError<Repr> {
+        TryFromReprError::new(value, enum_name)
     }
 
-    let signature;
-    let value;
-    match repr {
-        Some(ref repr) => {
-            signature = quote! {
-                fn n(value: #repr)
-            };
-            value = quote!(value);
-        }
-        None => {
-            repr = Some(quote!(i64));
-            signature = quote! {
-                fn n<REPR: Into<i64>>(value: REPR)
-            };
-            value = quote! {
-                <REPR as Into<i64>>::into(value)
-            };
-        }
+    pub fn parse_enum_error(
+        enum_name: &'static str,
+        choices: &'static [&'static str],
+    ) -> ParseEnumError {
+        ParseEnumError::new(enum_name, choices)
     }
 
-    let ident = input.ident;
-    let declare_discriminants = variants.iter().map(|variant| {
-        let variant = &variant.ident;
-        quote! {
-            const #variant: #repr = #ident::#variant as #repr;
-        }
-    });
-    let match_discriminants = variants.iter().map(|variant| {
-        let variant = &variant.ident;
-        quote! {
-            discriminant::#variant => Some(#ident::#variant),
+    // Splits a string of decimal or 0x-prefixed hexadecimal digits into the
+    // arguments for from_str_radix.
+    pub fn split_radix(s: &str) -> (&str, u32) {
+        match s.get(..2) {
+            Some("0x" | "0X") if !s[2..].starts_with(|ch| ch == '+' || ch == '-') => (&s[2..], 16),
+            _ => (s, 10),
         }
-    });
+    }
 
-    TokenStream::from(quote! {
-        impl #ident {
-            pub #signature -> Option<Self> {
-                #[allow(non_camel_case_types)]
-                struct discriminant;
-                #[allow(non_upper_case_globals)]
-                impl discriminant {
-                    #(#declare_discriminants)*
-                }
-                match #value {
-                    #(#match_discriminants)*
-                    _ => None,
-                }
-            }
-        }
-    })
+    pub fn iter<E>(variants: &'static [E], order: &'static [usize], copy: fn(&E) -> E) -> Iter<E> {
+        Iter::new(variants, order, copy)
+    }
 }
@@ -1,4 +1,4 @@
-use enumn::N;
+use enumn::{FromRepr, N};
 
 #[derive(Debug, N, PartialEq)]
 enum EmptyEnum {}
@@ -50,3 +50,1132 @@ fn test_discriminant() {
     assert_eq!(EnumWithDiscriminant::n(-80), Some(EnumWithDiscriminant::C));
     assert_eq!(EnumWithDiscriminant::n(12), None);
 }
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(try_from, try_n, name)]
+enum EnumWithOptIns {
+    Case0,
+    Case1,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(from_repr_trait, try_from, try_n, value)]
+enum EnumWithReprAndOptIns {
+    Case0,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(discriminant, from_repr_trait, iter, try_from, value, variants)]
+enum EnumWithDiscriminantAndOptIns {
+    A = 10,
+    B,
+    C = -80,
+}
+
+#[test]
+fn test_try_from() {
+    use std::convert::TryFrom;
+
+    assert_eq!(
+        EnumWithReprAndOptIns::try_from(0u8),
+        Ok(EnumWithReprAndOptIns::Case0),
+    );
+    let err = EnumWithReprAndOptIns::try_from(255u8).unwrap_err();
+    assert_eq!(err.value(), 255u8);
+
+    assert_eq!(
+        EnumWithDiscriminantAndOptIns::try_from(-80i8),
+        Ok(EnumWithDiscriminantAndOptIns::C),
+    );
+    assert_eq!(
+        EnumWithDiscriminantAndOptIns::try_from(11u32),
+        Ok(EnumWithDiscriminantAndOptIns::B),
+    );
+    let err = EnumWithDiscriminantAndOptIns::try_from(12i64).unwrap_err();
+    assert_eq!(err.value(), 12i64);
+}
+
+#[test]
+fn test_try_n() {
+    assert_eq!(EnumWithOptIns::try_n(1), Ok(EnumWithOptIns::Case1));
+    let err = EnumWithOptIns::try_n(4u16).unwrap_err();
+    assert_eq!(err.value(), 4u16);
+    assert_eq!(err.enum_name(), "EnumWithOptIns");
+    assert_eq!(
+        err.to_string(),
+        "4 is not a valid discriminant of enum EnumWithOptIns",
+    );
+
+    let err = EnumWithReprAndOptIns::try_n(255).unwrap_err();
+    assert_eq!(err.value(), 255u8);
+    let err: &dyn std::error::Error = &err;
+    assert_eq!(
+        err.to_string(),
+        "255 is not a valid discriminant of enum EnumWithReprAndOptIns",
+    );
+}
+
+#[test]
+fn test_value() {
+    assert_eq!(EnumWithReprAndOptIns::Case0.value(), 0u8);
+    assert_eq!(u8::from(EnumWithReprAndOptIns::Case0), 0);
+    assert_eq!(u8::from(&EnumWithReprAndOptIns::Case0), 0);
+
+    assert_eq!(EnumWithDiscriminantAndOptIns::B.value(), 11i64);
+    assert_eq!(i64::from(EnumWithDiscriminantAndOptIns::C), -80);
+    assert_eq!(i64::from(&EnumWithDiscriminantAndOptIns::A), 10);
+
+    const VALUE: u8 = EnumWithReprAndOptIns::Case0.value();
+    assert_eq!(VALUE, 0);
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+enum EnumWithOwnConversions {
+    Case0,
+    Case1,
+}
+
+impl EnumWithOwnConversions {
+    const COUNT: u8 = 2;
+}
+
+impl From<EnumWithOwnConversions> for u8 {
+    fn from(value: EnumWithOwnConversions) -> Self {
+        value as u8 + b'0'
+    }
+}
+
+impl std::convert::TryFrom<u8> for EnumWithOwnConversions {
+    type Error = char;
+
+    fn try_from(value: u8) -> Result<Self, char> {
+        value
+            .checked_sub(b'0')
+            .and_then(EnumWithOwnConversions::n)
+            .ok_or(value as char)
+    }
+}
+
+#[test]
+fn test_own_conversions() {
+    assert_eq!(
+        EnumWithOwnConversions::n(1),
+        Some(EnumWithOwnConversions::Case1),
+    );
+    assert_eq!(u8::from(EnumWithOwnConversions::Case1), b'1');
+    assert_eq!(
+        EnumWithOwnConversions::try_from(b'0'),
+        Ok(EnumWithOwnConversions::Case0),
+    );
+    assert_eq!(EnumWithOwnConversions::try_from(b'2'), Err('2'));
+    assert_eq!(
+        EnumWithOwnConversions::n(EnumWithOwnConversions::COUNT),
+        None
+    );
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(from_repr_trait)]
+enum EnumWithDefault {
+    Ping = 1,
+    Pong = 2,
+    #[enumn(default)]
+    Unknown = 0xFF,
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(EnumWithDefault::n(1), Some(EnumWithDefault::Ping));
+    assert_eq!(EnumWithDefault::n(3), None);
+    assert_eq!(EnumWithDefault::n_or_default(2), EnumWithDefault::Pong);
+    assert_eq!(EnumWithDefault::n_or_default(3), EnumWithDefault::Unknown);
+    assert_eq!(EnumWithDefault::from(0xFF), EnumWithDefault::Unknown);
+    assert_eq!(EnumWithDefault::from(0), EnumWithDefault::Unknown);
+}
+
+#[rustversion::since(1.66)]
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(discriminant, from_repr_trait, iter, name, value, variants)]
+enum OpenEnum {
+    Tcp = 6,
+    Udp = 17,
+    Udplite, // implicitly 18
+    #[enumn(other)]
+    Other(u8),
+}
+
+#[derive(Debug, N, PartialEq)]
+enum OpenEnumWithoutRepr {
+    Case0,
+    #[enumn(other)]
+    Other(i64),
+    Case2,
+}
+
+#[rustversion::since(1.66)]
+#[test]
+fn test_other() {
+    assert_eq!(OpenEnum::n(17), Some(OpenEnum::Udp));
+    assert_eq!(OpenEnum::n(41), None);
+    assert_eq!(OpenEnum::from_repr(6), OpenEnum::Tcp);
+    assert_eq!(OpenEnum::from_repr(18), OpenEnum::Udplite);
+    assert_eq!(OpenEnum::from_repr(41), OpenEnum::Other(41));
+    assert_eq!(OpenEnum::from(41), OpenEnum::Other(41));
+    for byte in 0..=u8::MAX {
+        assert_eq!(OpenEnum::from_repr(byte).to_repr(), byte);
+    }
+    assert_eq!(OpenEnum::Udplite.value(), 18);
+    assert_eq!(u8::from(OpenEnum::Other(41)), 41);
+    assert_eq!(u8::from(&OpenEnum::Tcp), 6);
+    assert_eq!(<OpenEnum as FromRepr>::n(7), None);
+    assert_eq!(OpenEnum::Udplite.discriminant(), 18);
+    assert_eq!(OpenEnum::Other(41).discriminant(), 19);
+    assert_eq!(OpenEnum::Other(41).value(), 41);
+
+    const OTHER: OpenEnum = OpenEnum::from_repr(7);
+    assert_eq!(OTHER, OpenEnum::Other(7));
+
+    assert_eq!(
+        OpenEnum::VARIANTS,
+        [OpenEnum::Tcp, OpenEnum::Udp, OpenEnum::Udplite]
+    );
+    assert_eq!(OpenEnum::DISCRIMINANTS, [6u8, 17, 18]);
+
+    let mut iter = OpenEnum::iter();
+    assert_eq!(iter.next_back(), Some(OpenEnum::Udplite));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(OpenEnum::Tcp));
+
+    assert_eq!(OpenEnum::Other(41).name(), "Other");
+    assert_eq!(OpenEnum::from_name("Udp"), Some(OpenEnum::Udp));
+    assert_eq!(OpenEnum::from_name("Other"), None);
+}
+
+#[test]
+fn test_other_without_repr() {
+    assert_eq!(OpenEnumWithoutRepr::n(1), None);
+    assert_eq!(OpenEnumWithoutRepr::n(2), Some(OpenEnumWithoutRepr::Case2));
+    assert_eq!(
+        OpenEnumWithoutRepr::from_repr(0),
+        OpenEnumWithoutRepr::Case0
+    );
+    assert_eq!(
+        OpenEnumWithoutRepr::from_repr(1),
+        OpenEnumWithoutRepr::Other(1)
+    );
+    assert_eq!(OpenEnumWithoutRepr::Other(-9).to_repr(), -9);
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(value, name, variants)]
+enum EnumWithCfg {
+    A,
+    #[cfg(not(test))]
+    Disabled,
+    B, // implicitly 1 because Disabled is configured out
+    #[cfg(test)]
+    Enabled,
+    #[cfg_attr(test, cfg(not(test)))]
+    DisabledByCfgAttr,
+    #[cfg_attr(not(test), cfg(not(test)))]
+    EnabledByCfgAttr,
+    #[cfg_attr(test, doc = "not a cfg")]
+    C,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+enum OpenEnumWithCfg {
+    A,
+    #[cfg(not(test))]
+    Disabled,
+    B,
+    #[cfg(test)]
+    Enabled,
+    C,
+    #[enumn(other)]
+    Other(u8),
+}
+
+// The compiler removes configured-out variants before the derive sees the
+// enum, so a #[cfg] on a variant is no different from the variant being absent.
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(name, strategy = "dense")]
+enum EnumWithCfgDefault {
+    A,
+    #[cfg(test)]
+    #[enumn(rename = "b")]
+    B,
+    #[cfg(not(test))]
+    #[enumn(rename = "b")]
+    Disabled,
+    #[cfg(test)]
+    #[enumn(default)]
+    Unknown,
+}
+
+#[test]
+fn test_cfg() {
+    assert_eq!(EnumWithCfg::n(0), Some(EnumWithCfg::A));
+    assert_eq!(EnumWithCfg::n(1), Some(EnumWithCfg::B));
+    assert_eq!(EnumWithCfg::n(2), Some(EnumWithCfg::Enabled));
+    assert_eq!(EnumWithCfg::n(3), Some(EnumWithCfg::EnabledByCfgAttr));
+    assert_eq!(EnumWithCfg::n(4), Some(EnumWithCfg::C));
+    assert_eq!(EnumWithCfg::n(5), None);
+    assert_eq!(EnumWithCfg::C.value(), 4);
+
+    assert_eq!(OpenEnumWithCfg::from_repr(1), OpenEnumWithCfg::B);
+    assert_eq!(OpenEnumWithCfg::from_repr(2), OpenEnumWithCfg::Enabled);
+    assert_eq!(OpenEnumWithCfg::from_repr(3), OpenEnumWithCfg::C);
+    assert_eq!(OpenEnumWithCfg::from_repr(4), OpenEnumWithCfg::Other(4));
+    assert_eq!(OpenEnumWithCfg::C.to_repr(), 3);
+
+    assert_eq!(EnumWithCfgDefault::n(1), Some(EnumWithCfgDefault::B));
+    assert_eq!(
+        EnumWithCfgDefault::n_or_default(2),
+        EnumWithCfgDefault::Unknown
+    );
+    assert_eq!(
+        EnumWithCfgDefault::n_or_default(9),
+        EnumWithCfgDefault::Unknown
+    );
+    assert_eq!(EnumWithCfgDefault::B.name(), "b");
+}
+
+#[rustversion::since(1.66)]
+#[derive(Debug, N, PartialEq)]
+#[repr(C, u8)]
+enum EnumWithReprCU8 {
+    A = 1,
+    #[enumn(other)]
+    Other(u8),
+}
+
+#[rustversion::since(1.66)]
+#[derive(Debug, N, PartialEq)]
+#[repr(u16, C)]
+enum EnumWithReprU16C {
+    A = 1,
+    #[enumn(other)]
+    Other(u16),
+}
+
+#[rustversion::since(1.66)]
+#[derive(Debug, N, PartialEq)]
+#[repr(C)]
+#[repr(align(4), i8)]
+enum EnumWithSeparateReprs {
+    A = -1,
+    #[enumn(other)]
+    Other(i8),
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(C)]
+enum EnumWithReprC {
+    A = 1,
+}
+
+#[rustversion::since(1.66)]
+#[test]
+fn test_compound_repr() {
+    let value: u8 = 1;
+    assert_eq!(EnumWithReprCU8::n(value), Some(EnumWithReprCU8::A));
+    let value: u16 = 2;
+    assert_eq!(
+        EnumWithReprU16C::from_repr(value),
+        EnumWithReprU16C::Other(2)
+    );
+    let value: i8 = -1;
+    assert_eq!(
+        EnumWithSeparateReprs::n(value),
+        Some(EnumWithSeparateReprs::A)
+    );
+}
+
+#[test]
+fn test_repr_c() {
+    let value: std::os::raw::c_int = 1;
+    assert_eq!(EnumWithReprC::n(value), Some(EnumWithReprC::A));
+    assert_eq!(EnumWithReprC::n(2), None);
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(from_repr_trait, repr_type = i128, value)]
+enum EnumWithReprType {
+    A,
+    #[enumn(other)]
+    Other(i128),
+}
+
+#[test]
+fn test_wide_inputs() {
+    assert_eq!(SimpleEnum::n(1u64), Some(SimpleEnum::Case1));
+    assert_eq!(SimpleEnum::n(1usize), Some(SimpleEnum::Case1));
+    assert_eq!(SimpleEnum::n(1u128), Some(SimpleEnum::Case1));
+    assert_eq!(SimpleEnum::n(u64::MAX), None);
+    assert_eq!(SimpleEnum::n(i128::MIN), None);
+    assert_eq!(
+        EnumWithDiscriminant::n(-80isize),
+        Some(EnumWithDiscriminant::C)
+    );
+    assert_eq!(EnumWithDiscriminant::n(u128::MAX), None);
+
+    let err = EnumWithOptIns::try_n(u64::MAX).unwrap_err();
+    assert_eq!(err.value(), u64::MAX);
+    assert_eq!(
+        EnumWithOptIns::try_from(u128::MAX).unwrap_err().value(),
+        u128::MAX,
+    );
+
+    assert_eq!(EnumWithReprType::n(0i128), Some(EnumWithReprType::A));
+    assert_eq!(EnumWithReprType::n(u128::MAX), None);
+    assert_eq!(
+        EnumWithReprType::from_repr(u64::MAX),
+        EnumWithReprType::Other(i128::from(u64::MAX)),
+    );
+    assert_eq!(EnumWithReprType::Other(-1).value(), -1i128);
+}
+
+#[test]
+fn test_const() {
+    const CASE0: Option<EnumWithRepr> = EnumWithRepr::n(0);
+    assert_eq!(CASE0, Some(EnumWithRepr::Case0));
+
+    static TABLE: [Option<EnumWithDefault>; 3] = [
+        EnumWithDefault::n(0),
+        EnumWithDefault::n(1),
+        EnumWithDefault::n(2),
+    ];
+    assert_eq!(
+        TABLE,
+        [
+            None,
+            Some(EnumWithDefault::Ping),
+            Some(EnumWithDefault::Pong)
+        ],
+    );
+
+    const UNKNOWN: EnumWithDefault = EnumWithDefault::n_or_default(7);
+    assert_eq!(UNKNOWN, EnumWithDefault::Unknown);
+
+    const C: Option<EnumWithDiscriminant> = EnumWithDiscriminant::n_i64(-80);
+    assert_eq!(C, Some(EnumWithDiscriminant::C));
+    const A: Option<EnumWithReprType> = EnumWithReprType::n_i128(0);
+    assert_eq!(A, Some(EnumWithReprType::A));
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(iter, variants)]
+enum EmptyEnumWithVariants {}
+
+#[derive(Debug, N, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+#[enumn(iter, variants)]
+enum EnumWithReservedNames {
+    SUM,
+    COUNT,
+    VARIANTS,
+    DISCRIMINANTS,
+    ORDER,
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(EmptyEnumWithVariants::VARIANTS, []);
+    assert_eq!(EmptyEnumWithVariants::COUNT, 0);
+
+    assert_eq!(
+        EnumWithDiscriminantAndOptIns::VARIANTS,
+        [
+            EnumWithDiscriminantAndOptIns::A,
+            EnumWithDiscriminantAndOptIns::B,
+            EnumWithDiscriminantAndOptIns::C,
+        ],
+    );
+    assert_eq!(EnumWithDiscriminantAndOptIns::COUNT, 3);
+    assert_eq!(EnumWithDiscriminantAndOptIns::DISCRIMINANTS, [10, 11, -80]);
+
+    assert_eq!(EnumWithCfg::COUNT, 5);
+    assert_eq!(EnumWithCfg::DISCRIMINANTS, [0, 1, 2, 3, 4]);
+
+    assert_eq!(
+        EnumWithReservedNames::n(1),
+        Some(EnumWithReservedNames::COUNT)
+    );
+    assert_eq!(
+        EnumWithReservedNames::iter().next_back(),
+        Some(EnumWithReservedNames::ORDER),
+    );
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(iter, iter_order = "declaration")]
+enum EnumWithDeclarationOrder {
+    A = 10,
+    B, // implicitly 11
+    C = -80,
+}
+
+#[test]
+fn test_iter() {
+    assert_eq!(EmptyEnumWithVariants::iter().next(), None);
+
+    let iter = EnumWithDiscriminantAndOptIns::iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [
+            EnumWithDiscriminantAndOptIns::C,
+            EnumWithDiscriminantAndOptIns::A,
+            EnumWithDiscriminantAndOptIns::B,
+        ],
+    );
+    assert_eq!(
+        EnumWithDiscriminantAndOptIns::iter()
+            .rev()
+            .collect::<Vec<_>>(),
+        [
+            EnumWithDiscriminantAndOptIns::B,
+            EnumWithDiscriminantAndOptIns::A,
+            EnumWithDiscriminantAndOptIns::C,
+        ],
+    );
+
+    assert_eq!(
+        EnumWithDeclarationOrder::iter().collect::<Vec<_>>(),
+        [
+            EnumWithDeclarationOrder::A,
+            EnumWithDeclarationOrder::B,
+            EnumWithDeclarationOrder::C,
+        ],
+    );
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(name, rename_all = "snake_case")]
+enum EnumWithRenames {
+    LowLatency,
+    #[enumn(rename = "bulk")]
+    HighThroughput,
+    r#Default,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(name, rename_all = "camelCase")]
+enum EnumWithNonAsciiCamelCase {
+    Été,
+    ÉtéIndien,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[enumn(name, rename_all = "snake_case")]
+enum EnumWithNonAsciiSnakeCase {
+    HiverÉternel,
+}
+
+#[test]
+fn test_name() {
+    assert_eq!(EnumWithOptIns::Case0.name(), "Case0");
+    assert_eq!(
+        EnumWithOptIns::from_name("Case1"),
+        Some(EnumWithOptIns::Case1),
+    );
+    assert_eq!(EnumWithOptIns::from_name("case1"), None);
+
+    assert_eq!(EnumWithRenames::LowLatency.name(), "low_latency");
+    assert_eq!(EnumWithRenames::HighThroughput.name(), "bulk");
+    assert_eq!(EnumWithRenames::r#Default.name(), "default");
+    assert_eq!(
+        EnumWithRenames::from_name("low_latency"),
+        Some(EnumWithRenames::LowLatency),
+    );
+    assert_eq!(
+        EnumWithRenames::from_name("bulk"),
+        Some(EnumWithRenames::HighThroughput),
+    );
+    assert_eq!(EnumWithRenames::from_name("high_throughput"), None);
+
+    assert_eq!(EnumWithNonAsciiCamelCase::Été.name(), "été");
+    assert_eq!(EnumWithNonAsciiCamelCase::ÉtéIndien.name(), "étéIndien");
+    assert_eq!(
+        EnumWithNonAsciiSnakeCase::HiverÉternel.name(),
+        "hiver_éternel",
+    );
+
+    assert_eq!(
+        EnumWithCfg::from_name("Enabled"),
+        Some(EnumWithCfg::Enabled)
+    );
+    assert_eq!(EnumWithCfg::from_name("Disabled"), None);
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(from_str, rename_all = "lowercase")]
+enum EnumWithFromStr {
+    Tcp = 6,
+    #[enumn(alias_name = "datagram", alias_name = "user-datagram")]
+    Udp = 17,
+    #[cfg(not(test))]
+    Sctp = 132,
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("tcp".parse(), Ok(EnumWithFromStr::Tcp));
+    assert_eq!("TCP".parse(), Ok(EnumWithFromStr::Tcp));
+    assert_eq!("Datagram".parse(), Ok(EnumWithFromStr::Udp));
+    assert_eq!("user-datagram".parse(), Ok(EnumWithFromStr::Udp));
+    assert_eq!("17".parse(), Ok(EnumWithFromStr::Udp));
+    assert_eq!("0x11".parse(), Ok(EnumWithFromStr::Udp));
+    assert_eq!("0X06".parse(), Ok(EnumWithFromStr::Tcp));
+
+    for invalid in ["sctp", "18", "0x", "0x+6", "256", "", " tcp"] {
+        let err = invalid.parse::<EnumWithFromStr>().unwrap_err();
+        assert_eq!(err.enum_name(), "EnumWithFromStr");
+        assert_eq!(err.choices(), ["tcp", "udp"]);
+        assert_eq!(
+            err.to_string(),
+            "expected one of `tcp`, `udp`, or a discriminant of enum EnumWithFromStr",
+        );
+    }
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(value, variants)]
+enum EnumWithAliases {
+    #[enumn(alias = 0x11)]
+    Idle = 0x10,
+    #[enumn(alias = 0x20..=0x2F, alias = 0x31)]
+    Busy = 0x30,
+    #[cfg(not(test))]
+    #[enumn(alias = 0x40)]
+    Disabled = 0x41,
+    #[enumn(default)]
+    Unknown = 0xFF,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[allow(non_camel_case_types)]
+enum EnumWithNegativeAlias {
+    #[enumn(alias = -5..=-1)]
+    Negative = -6,
+    Zero = 0,
+    check_aliases = 1,
+}
+
+#[test]
+fn test_alias() {
+    assert_eq!(EnumWithAliases::n(0x10), Some(EnumWithAliases::Idle));
+    assert_eq!(EnumWithAliases::n(0x11), Some(EnumWithAliases::Idle));
+    assert_eq!(EnumWithAliases::n(0x12), None);
+    assert_eq!(EnumWithAliases::n(0x20), Some(EnumWithAliases::Busy));
+    assert_eq!(EnumWithAliases::n(0x2F), Some(EnumWithAliases::Busy));
+    assert_eq!(EnumWithAliases::n(0x31), Some(EnumWithAliases::Busy));
+    assert_eq!(EnumWithAliases::n(0x40), None);
+    assert_eq!(EnumWithAliases::n_or_default(0x25), EnumWithAliases::Busy,);
+    assert_eq!(EnumWithAliases::Busy.value(), 0x30);
+    assert_eq!(EnumWithAliases::DISCRIMINANTS, [0x10, 0x30, 0xFF]);
+
+    assert_eq!(
+        EnumWithNegativeAlias::n(-3),
+        Some(EnumWithNegativeAlias::Negative),
+    );
+    assert_eq!(
+        EnumWithNegativeAlias::n(1),
+        Some(EnumWithNegativeAlias::check_aliases),
+    );
+    assert_eq!(EnumWithNegativeAlias::n(2), None);
+}
+
+#[rustfmt::skip]
+#[derive(Debug, N, PartialEq)]
+#[repr(i8)]
+#[enumn(iter, strategy = "dense")]
+enum DenseEnum {
+    M8 = -8, M7, M6, M5, M4, M3, M2, M1,
+    P0, P1, P2, P3, P4, P5, P6, P7,
+    P8, P9, P10, P11,
+}
+
+#[rustfmt::skip]
+#[derive(Debug, N, PartialEq)]
+#[repr(u16)]
+enum SparseEnum {
+    V0, V1, V2, V3, V4, V5, V6, V7,
+    V8, V9, V10, V11, V12, V13, V14, V15,
+    V17 = 17,
+}
+
+#[test]
+fn test_dense() {
+    assert_eq!(DenseEnum::n(-9), None);
+    assert_eq!(DenseEnum::n(-8), Some(DenseEnum::M8));
+    assert_eq!(DenseEnum::n(0), Some(DenseEnum::P0));
+    assert_eq!(DenseEnum::n(11), Some(DenseEnum::P11));
+    assert_eq!(DenseEnum::n(12), None);
+    assert_eq!(DenseEnum::n(i8::MAX), None);
+    for (i, variant) in DenseEnum::iter().enumerate() {
+        assert_eq!(DenseEnum::n(i as i8 - 8), Some(variant));
+    }
+
+    assert_eq!(SparseEnum::n(15), Some(SparseEnum::V15));
+    assert_eq!(SparseEnum::n(16), None);
+    assert_eq!(SparseEnum::n(17), Some(SparseEnum::V17));
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(i32)]
+#[enumn(strategy = "binary_search")]
+#[allow(clippy::upper_case_acronyms)]
+enum EnumWithBinarySearch {
+    NotFound = 404,
+    Ok = 200,
+    Negative = -1_000_000,
+    #[cfg(not(test))]
+    Teapot = 418,
+    #[cfg(test)]
+    Huge = i32::MAX,
+    Continue = 100,
+    SORTED = 1,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(strategy = "dense")]
+enum EnumWithDenseStrategy {
+    A = 2,
+    B = 4,
+    C = 3,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(strategy = "match")]
+enum EnumWithMatchStrategy {
+    A,
+    B,
+}
+
+#[test]
+fn test_strategy() {
+    assert_eq!(
+        EnumWithBinarySearch::n(-1_000_000),
+        Some(EnumWithBinarySearch::Negative),
+    );
+    assert_eq!(
+        EnumWithBinarySearch::n(100),
+        Some(EnumWithBinarySearch::Continue)
+    );
+    assert_eq!(EnumWithBinarySearch::n(200), Some(EnumWithBinarySearch::Ok));
+    assert_eq!(
+        EnumWithBinarySearch::n(1),
+        Some(EnumWithBinarySearch::SORTED)
+    );
+    assert_eq!(
+        EnumWithBinarySearch::n(404),
+        Some(EnumWithBinarySearch::NotFound)
+    );
+    assert_eq!(
+        EnumWithBinarySearch::n(i32::MAX),
+        Some(EnumWithBinarySearch::Huge)
+    );
+    for value in [i32::MIN, -1, 0, 101, 418, 500, i32::MAX - 1] {
+        assert_eq!(EnumWithBinarySearch::n(value), None);
+    }
+
+    const FOUND: Option<EnumWithBinarySearch> = EnumWithBinarySearch::n(200);
+    assert_eq!(FOUND, Some(EnumWithBinarySearch::Ok));
+
+    assert_eq!(EnumWithDenseStrategy::n(1), None);
+    assert_eq!(EnumWithDenseStrategy::n(2), Some(EnumWithDenseStrategy::A));
+    assert_eq!(EnumWithDenseStrategy::n(3), Some(EnumWithDenseStrategy::C));
+    assert_eq!(EnumWithDenseStrategy::n(4), Some(EnumWithDenseStrategy::B));
+    assert_eq!(EnumWithDenseStrategy::n(5), None);
+
+    assert_eq!(EnumWithMatchStrategy::n(1), Some(EnumWithMatchStrategy::B));
+    assert_eq!(EnumWithMatchStrategy::n(2), None);
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8, align(2))]
+enum EnumWithAlign {
+    A,
+    B,
+}
+
+#[test]
+fn test_align() {
+    assert_eq!(EnumWithAlign::n(1), Some(EnumWithAlign::B));
+    assert_eq!(EnumWithAlign::n(2), None);
+}
+
+#[test]
+fn test_n_unchecked() {
+    unsafe {
+        assert_eq!(EnumWithRepr::n_unchecked(0), EnumWithRepr::Case0);
+        assert_eq!(DenseEnum::n_unchecked(-8), DenseEnum::M8);
+        assert_eq!(DenseEnum::n_unchecked(11), DenseEnum::P11);
+        assert_eq!(
+            EnumWithBinarySearch::n_unchecked(404),
+            EnumWithBinarySearch::NotFound,
+        );
+        assert_eq!(EnumWithAliases::n_unchecked(0x10), EnumWithAliases::Idle);
+        assert_eq!(EnumWithAliases::n_unchecked(0x25), EnumWithAliases::Busy);
+        assert_eq!(EnumWithAlign::n_unchecked(0), EnumWithAlign::A);
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "n_unchecked called with a value that is not a discriminant of EnumWithRepr"]
+fn test_n_unchecked_invalid() {
+    let _ = unsafe { EnumWithRepr::n_unchecked(1) };
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(exhaustive)]
+enum EnumWithFullCoverage {
+    #[enumn(alias = 1..=0x7F)]
+    Low = 0,
+    #[enumn(alias = 0x81..=0xFE)]
+    High = 0x80,
+    Max = 0xFF,
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(i8)]
+enum EnumWithSignedCoverage {
+    #[enumn(alias = -127..=-1)]
+    Negative = -128,
+    #[enumn(alias = 1..=127)]
+    Positive = 0,
+}
+
+#[test]
+fn test_total() {
+    assert_eq!(
+        EnumWithFullCoverage::n_total(0x00),
+        EnumWithFullCoverage::Low
+    );
+    assert_eq!(
+        EnumWithFullCoverage::n_total(0x7F),
+        EnumWithFullCoverage::Low
+    );
+    assert_eq!(
+        EnumWithFullCoverage::n_total(0x80),
+        EnumWithFullCoverage::High
+    );
+    assert_eq!(EnumWithFullCoverage::from(0xFE), EnumWithFullCoverage::High);
+    assert_eq!(EnumWithFullCoverage::from(0xFF), EnumWithFullCoverage::Max);
+
+    const TOTAL: EnumWithSignedCoverage = EnumWithSignedCoverage::n_total(-5);
+    assert_eq!(TOTAL, EnumWithSignedCoverage::Negative);
+    assert_eq!(
+        EnumWithSignedCoverage::from(i8::MAX),
+        EnumWithSignedCoverage::Positive,
+    );
+}
+
+#[test]
+fn test_from_repr_trait() {
+    fn decode<E: FromRepr<Repr = u8>>(bytes: &[u8]) -> Vec<Option<E>> {
+        bytes.iter().map(|&byte| E::n(byte)).collect()
+    }
+
+    assert_eq!(
+        decode::<EnumWithReprAndOptIns>(&[0, 7]),
+        [Some(EnumWithReprAndOptIns::Case0), None],
+    );
+    assert_eq!(
+        decode::<EnumWithDefault>(&[1, 7]),
+        [Some(EnumWithDefault::Ping), None],
+    );
+
+    fn n<E: FromRepr>(value: E::Repr) -> Option<E> {
+        E::n(value)
+    }
+
+    assert_eq!(
+        n::<EnumWithDiscriminantAndOptIns>(-80),
+        Some(EnumWithDiscriminantAndOptIns::C)
+    );
+    assert_eq!(n::<EnumWithReprType>(i128::MAX), None);
+}
+
+#[rustversion::since(1.66)]
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(kind = MsgKind, discriminant, name, rename_all = "snake_case", value)]
+enum Msg<'a, T: Clone> {
+    Ping = 1,
+    Data(&'a [u8]) = 4,
+    Close {
+        code: u16,
+    },
+    #[cfg(not(test))]
+    Disabled,
+    #[enumn(rename = "custom")]
+    Custom(T),
+}
+
+#[rustversion::since(1.66)]
+#[test]
+fn test_kind() {
+    let msg: Msg<()> = Msg::Data(b"payload");
+    assert_eq!(msg.kind(), MsgKind::Data);
+    let msg: Msg<()> = Msg::Close { code: 1000 };
+    assert_eq!(msg.kind(), MsgKind::Close);
+    let msg: Msg<()> = Msg::Ping;
+    assert_eq!(msg.kind(), MsgKind::Ping);
+    assert_eq!(Msg::Custom(()).kind().name(), "custom");
+
+    assert_eq!(MsgKind::n(1), Some(MsgKind::Ping));
+    assert_eq!(MsgKind::n(4), Some(MsgKind::Data));
+    assert_eq!(MsgKind::n(5), Some(MsgKind::Close));
+    assert_eq!(MsgKind::n(6), Some(MsgKind::Custom));
+    assert_eq!(MsgKind::Close.value(), 5);
+    assert_eq!(MsgKind::from_name("ping"), Some(MsgKind::Ping));
+
+    let msg: Msg<()> = Msg::Data(b"payload");
+    assert_eq!(msg.discriminant(), 4);
+    let msg: Msg<()> = Msg::Close { code: 1000 };
+    assert_eq!(msg.discriminant(), 5);
+}
+
+#[rustversion::since(1.66)]
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(default_fields, discriminant, from_str, name, value, variants)]
+enum Packet {
+    Ack = 1,
+    Data(Vec<u8>, u16) = 4,
+    Close {
+        code: u16,
+        reason: Option<String>,
+    },
+    #[enumn(default)]
+    Unknown = 0xFF,
+}
+
+#[rustversion::since(1.66)]
+#[test]
+fn test_default_fields() {
+    assert_eq!(Packet::n(1), Some(Packet::Ack));
+    assert_eq!(Packet::n(4), Some(Packet::Data(Vec::new(), 0)));
+    assert_eq!(
+        Packet::n(5),
+        Some(Packet::Close {
+            code: 0,
+            reason: None,
+        }),
+    );
+    assert_eq!(Packet::n(6), None);
+    assert_eq!(Packet::n_or_default(6), Packet::Unknown);
+    assert_eq!(Packet::DISCRIMINANTS, [1, 4, 5, 0xFF]);
+    assert_eq!(Packet::COUNT, 4);
+
+    let packet = Packet::Data(vec![1, 2, 3], 3);
+    assert_eq!(packet.name(), "Data");
+    assert_eq!(u8::from(&packet), 4);
+    assert_eq!(packet.value(), 4);
+    assert_eq!(Packet::from_name("Close").map(u8::from), Some(5));
+    assert_eq!("data".parse(), Ok(Packet::Data(Vec::new(), 0)));
+
+    assert_eq!(Packet::Ack.discriminant(), 1);
+    assert_eq!(Packet::Data(vec![1, 2, 3], 3).discriminant(), 4);
+    let close = Packet::Close {
+        code: 1000,
+        reason: None,
+    };
+    assert_eq!(close.discriminant(), 5);
+}
+
+#[derive(Debug, N, PartialEq)]
+#[repr(u8)]
+#[enumn(
+    default_fields,
+    discriminant,
+    from_str,
+    name,
+    try_from,
+    try_n,
+    value,
+    variants
+)]
+enum GenericCmd<'a, T: Default>
+where
+    T: Clone,
+{
+    A(T),
+    B(&'a str),
+    C,
+}
+
+#[test]
+fn test_generics() {
+    assert_eq!(GenericCmd::n(0), Some(GenericCmd::A(0)));
+    assert_eq!(GenericCmd::<u16>::n(1), Some(GenericCmd::B("")));
+    assert_eq!(GenericCmd::<u16>::n(3), None);
+    assert_eq!(GenericCmd::try_from(2), Ok(GenericCmd::<u16>::C));
+    assert!(GenericCmd::<u16>::try_n(3).is_err());
+    assert_eq!("b".parse(), Ok(GenericCmd::<u16>::B("")));
+    assert_eq!(GenericCmd::A(9).name(), "A");
+    assert_eq!(GenericCmd::<u16>::from_name("C"), Some(GenericCmd::C));
+    assert_eq!(GenericCmd::<u16>::B("x").value(), 1);
+    assert_eq!(GenericCmd::<u16>::B("x").discriminant(), 1);
+    assert_eq!(u8::from(&GenericCmd::A(9)), 0);
+    assert_eq!(GenericCmd::<u16>::COUNT, 3);
+}
+
+#[test]
+fn test_discriminant_method() {
+    assert_eq!(EnumWithDiscriminantAndOptIns::C.discriminant(), -80);
+}
+
+#[derive(Clone, Copy, Debug, N, PartialEq)]
+#[enumn(set)]
+enum Feature {
+    Compression = 4,
+    #[cfg(not(test))]
+    Disabled,
+    Encryption = 1,
+    Multiplexing,
+}
+
+#[derive(Clone, Copy, Debug, N, PartialEq)]
+#[enumn(set)]
+#[allow(non_camel_case_types)]
+enum EnumWithReservedSetNames {
+    of,
+    variant,
+    ordinal,
+}
+
+#[rustfmt::skip]
+#[derive(Clone, Copy, Debug, N, PartialEq)]
+#[enumn(set = Wide)]
+enum WideEnum {
+    V0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15, V16,
+    V17, V18, V19, V20, V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31,
+    V32, V33, V34, V35, V36, V37, V38, V39, V40, V41, V42, V43, V44, V45, V46,
+    V47, V48, V49, V50, V51, V52, V53, V54, V55, V56, V57, V58, V59, V60, V61,
+    V62, V63, V64, V65, V66, V67, V68, V69, V70, V71, V72, V73, V74, V75, V76,
+    V77, V78, V79, V80, V81, V82, V83, V84, V85, V86, V87, V88, V89, V90, V91,
+    V92, V93, V94, V95, V96, V97, V98, V99, V100, V101, V102, V103, V104, V105,
+    V106, V107, V108, V109, V110, V111, V112, V113, V114, V115, V116, V117,
+    V118, V119, V120, V121, V122, V123, V124, V125, V126, V127, V128, V129,
+}
+
+#[test]
+fn test_set() {
+    const SUPPORTED: FeatureSet = FeatureSet::new()
+        .with(Feature::Compression)
+        .with(Feature::Multiplexing);
+
+    let mut set = SUPPORTED;
+    assert_eq!(set.bits(), 0b101);
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(Feature::Multiplexing));
+    assert!(!set.contains(Feature::Encryption));
+    assert!(set.insert(Feature::Encryption));
+    assert!(!set.insert(Feature::Encryption));
+    assert_eq!(set, FeatureSet::all());
+    assert!(set.remove(Feature::Compression));
+    assert!(!set.remove(Feature::Compression));
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        [Feature::Encryption, Feature::Multiplexing]
+    );
+    assert_eq!(format!("{:?}", set), "{Encryption, Multiplexing}");
+
+    assert_eq!(set.union(SUPPORTED), FeatureSet::all());
+    assert_eq!(
+        set.intersection(SUPPORTED).iter().collect::<Vec<_>>(),
+        [Feature::Multiplexing],
+    );
+    assert!(FeatureSet::default().is_empty());
+    assert_eq!(
+        FeatureSet::from_bits(0b011),
+        Some(
+            FeatureSet::new()
+                .with(Feature::Compression)
+                .with(Feature::Encryption)
+        )
+    );
+    assert_eq!(FeatureSet::from_bits(0b1000), None);
+    assert_eq!(
+        [Feature::Encryption, Feature::Compression]
+            .iter()
+            .copied()
+            .collect::<FeatureSet>(),
+        FeatureSet::from_bits(0b011).unwrap(),
+    );
+
+    let mut wide = Wide::new();
+    assert_eq!(wide.bits(), [0; 3]);
+    assert!(wide.insert(WideEnum::V129));
+    assert!(wide.insert(WideEnum::V64));
+    assert_eq!(wide.bits(), [0, 1, 2]);
+    assert_eq!(
+        wide.iter().collect::<Vec<_>>(),
+        [WideEnum::V64, WideEnum::V129]
+    );
+    assert_eq!(Wide::all().len(), 130);
+    assert_eq!(Wide::from_bits([0, 0, 4]), None);
+    assert_eq!(Wide::from_bits([0, 0, 3]).map(|set| set.len()), Some(2));
+
+    let reserved = EnumWithReservedSetNamesSet::new()
+        .with(EnumWithReservedSetNames::variant)
+        .with(EnumWithReservedSetNames::ordinal);
+    assert!(!reserved.contains(EnumWithReservedSetNames::of));
+    assert_eq!(reserved.bits(), 0b110);
+    assert_eq!(format!("{:?}", reserved), "{variant, ordinal}");
+}
+
+mod facade {
+    pub use enumn;
+}
+
+#[derive(Debug, facade::enumn::N, PartialEq)]
+#[repr(C)]
+#[enumn(crate = facade::enumn, from_repr_trait, from_str, iter, try_from, try_n)]
+enum EnumWithCratePath {
+    A,
+    B,
+}
+
+#[derive(Debug, facade::enumn::N, PartialEq)]
+#[enumn(crate = facade::enumn, kind = EnumWithCratePathKind, iter)]
+enum EnumWithCratePathData {
+    A(u8),
+    B,
+}
+
+#[test]
+fn test_crate_path() {
+    assert_eq!(EnumWithCratePath::n(1), Some(EnumWithCratePath::B));
+    assert_eq!(EnumWithCratePath::try_n(2).unwrap_err().value(), 2);
+    assert_eq!(EnumWithCratePath::try_from(0), Ok(EnumWithCratePath::A));
+    assert_eq!("b".parse(), Ok(EnumWithCratePath::B));
+    assert_eq!(EnumWithCratePath::iter().len(), 2);
+    assert_eq!(
+        <EnumWithCratePath as FromRepr>::n(0),
+        Some(EnumWithCratePath::A),
+    );
+
+    assert_eq!(EnumWithCratePathData::A(9).kind(), EnumWithCratePathKind::A);
+    assert_eq!(EnumWithCratePathData::B.kind(), EnumWithCratePathKind::B);
+    assert_eq!(
+        EnumWithCratePathKind::iter().next(),
+        Some(EnumWithCratePathKind::A),
+    );
+}
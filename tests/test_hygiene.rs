@@ -0,0 +1,118 @@
+#![no_implicit_prelude]
+#![allow(dead_code, non_camel_case_types)]
+
+// Shadow the prelude and the primitive types, none of which the generated code
+// is allowed to rely on.
+struct Option;
+struct Some;
+struct None;
+struct Result;
+struct Ok;
+struct Err;
+struct From;
+struct Into;
+struct TryFrom;
+struct TryInto;
+struct Copy;
+struct Default;
+struct u8;
+struct i64;
+struct i128;
+struct c_int;
+struct str;
+struct usize;
+struct bool;
+struct Iterator;
+struct Debug;
+
+#[derive(::enumn::N)]
+#[enumn(name, set)]
+enum Plain {
+    A,
+    B = 10,
+}
+
+#[derive(::enumn::N)]
+#[repr(u8)]
+#[enumn(from_str)]
+enum WithRepr {
+    A,
+    #[cfg(not(test))]
+    B,
+    #[enumn(default)]
+    C,
+}
+
+#[derive(::enumn::N)]
+#[repr(C)]
+enum WithReprC {
+    A,
+}
+
+#[derive(::enumn::N)]
+#[enumn(from_repr_trait, repr_type = i128, name)]
+enum Open {
+    A,
+    #[enumn(other)]
+    Other(::core::primitive::i128),
+}
+
+#[derive(::enumn::N)]
+#[enumn(kind = WithDataKind, set)]
+enum WithData {
+    A(::core::primitive::u8),
+    B { b: ::core::primitive::i64 },
+}
+
+#[derive(::enumn::N)]
+#[repr(u8)]
+#[enumn(default_fields, discriminant)]
+enum DefaultFields {
+    A(::core::primitive::u8),
+    #[enumn(default)]
+    B {
+        b: ::core::primitive::i64,
+    },
+}
+
+#[::core::prelude::v1::test]
+fn test_hygiene() {
+    ::core::assert!(::core::matches!(
+        Plain::n(10),
+        ::core::option::Option::Some(Plain::B),
+    ));
+    ::core::assert!(::core::matches!(WithRepr::n_or_default(7), WithRepr::C));
+    ::core::assert!(::core::matches!(
+        unsafe { WithRepr::n_unchecked(0) },
+        WithRepr::A,
+    ));
+    ::core::assert!(::core::matches!(
+        <WithRepr as ::core::str::FromStr>::from_str("c"),
+        ::core::result::Result::Ok(WithRepr::C),
+    ));
+    ::core::assert!(::core::matches!(
+        WithReprC::n(0),
+        ::core::option::Option::Some(WithReprC::A)
+    ));
+    ::core::assert!(::core::matches!(Open::from_repr(7), Open::Other(7)));
+    ::core::assert!(::core::matches!(
+        WithData::B { b: 0 }.kind(),
+        WithDataKind::B,
+    ));
+    ::core::assert!(::core::matches!(
+        DefaultFields::n_or_default(7),
+        DefaultFields::B { b: 0 },
+    ));
+    ::core::assert_eq!(DefaultFields::A(1).discriminant(), 0);
+    ::core::assert!(::core::matches!(
+        <Open as ::enumn::FromRepr>::n(0),
+        ::core::option::Option::Some(Open::A),
+    ));
+    ::core::assert_eq!(Open::Other(7).name(), "Other");
+    ::core::assert!(PlainSet::new().with(Plain::B).contains(Plain::B));
+    ::core::assert!(WithDataKindSet::all().contains(WithDataKind::A));
+    ::core::assert!(::core::matches!(
+        Plain::from_name("A"),
+        ::core::option::Option::Some(Plain::A),
+    ));
+}
@@ -0,0 +1,17 @@
+use enumn::N;
+
+#[derive(N)]
+enum E {
+    #[enumn(alias_name = "a")]
+    A,
+}
+
+#[derive(N)]
+#[enumn(from_str)]
+enum F {
+    A,
+    #[enumn(alias_name = "a")]
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use enumn::N;
+
+#[derive(N)]
+#[repr(u8)]
+enum Literal {
+    #[enumn(alias = 0x11)]
+    A = 0x10,
+    #[enumn(alias = 0x10..=0x1F)]
+    B = 0x20,
+    #[enumn(alias = 0x18)]
+    C = 0x30,
+}
+
+const BASE: u8 = 0x40;
+
+#[derive(N)]
+#[repr(u8)]
+enum Computed {
+    #[enumn(alias = 0x41)]
+    A = BASE,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumn::N;
+
+#[derive(N)]
+#[repr(u8, u16)]
+enum E {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumn::N;
+
+#[derive(N)]
+enum Message {
+    Ping,
+    Data(Vec<u8>),
+    Close { code: u16 },
+}
+
+fn main() {}
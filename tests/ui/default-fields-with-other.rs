@@ -0,0 +1,13 @@
+use enumn::N;
+
+#[derive(N)]
+#[repr(u8)]
+#[enumn(default_fields)]
+enum Message {
+    Ping,
+    Data(Vec<u8>),
+    #[enumn(other)]
+    Unknown(u8),
+}
+
+fn main() {}
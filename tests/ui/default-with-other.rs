@@ -0,0 +1,12 @@
+use enumn::N;
+
+#[derive(N)]
+#[repr(u8)]
+enum E {
+    #[enumn(default)]
+    A,
+    #[enumn(other)]
+    Other(u8),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use enumn::N;
+
+#[derive(N)]
+#[enumn(default_fields, discriminant)]
+enum Message {
+    Ping,
+    Data(Vec<u8>),
+}
+
+#[derive(N)]
+#[enumn(kind = EventKind, discriminant)]
+enum Event {
+    Start,
+    Stop { code: u16 },
+}
+
+fn main() {}
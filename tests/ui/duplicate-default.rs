@@ -0,0 +1,11 @@
+use enumn::N;
+
+#[derive(N)]
+enum E {
+    #[enumn(default)]
+    A,
+    #[enumn(default)]
+    B,
+}
+
+fn main() {}
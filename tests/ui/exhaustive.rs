@@ -0,0 +1,32 @@
+use enumn::N;
+
+#[derive(N)]
+#[repr(u8)]
+#[enumn(exhaustive)]
+enum Missing {
+    #[enumn(alias = 1..=9)]
+    A = 0,
+    B = 11,
+    #[enumn(alias = 20..=254)]
+    C = 19,
+}
+
+#[derive(N)]
+#[enumn(exhaustive)]
+enum NoRepr {
+    A,
+}
+
+const C: u8 = 2;
+
+#[derive(N)]
+#[repr(u8)]
+#[enumn(exhaustive)]
+enum NotLiteral {
+    #[enumn(alias = 0..=1)]
+    A = C,
+    #[enumn(alias = 3, alias = 5..=255)]
+    B = 4,
+}
+
+fn main() {}
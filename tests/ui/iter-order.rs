@@ -0,0 +1,22 @@
+use enumn::N;
+
+#[derive(N)]
+#[enumn(iter, iter_order = "alphabetical")]
+enum E {
+    A,
+}
+
+#[derive(N)]
+#[enumn(iter_order = "declaration")]
+enum WithoutIter {
+    A,
+}
+
+#[derive(N)]
+#[repr(u8)]
+#[enumn(default_fields, iter)]
+enum WithData {
+    A(u8),
+}
+
+fn main() {}
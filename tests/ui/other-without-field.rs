@@ -0,0 +1,10 @@
+use enumn::N;
+
+#[derive(N)]
+enum E {
+    A,
+    #[enumn(other)]
+    Other,
+}
+
+fn main() {}
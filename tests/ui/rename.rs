@@ -0,0 +1,16 @@
+use enumn::N;
+
+#[derive(N)]
+#[enumn(rename_all = "Title Case")]
+enum E {
+    A,
+}
+
+#[derive(N)]
+enum F {
+    A,
+    #[enumn(rename = "A")]
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use enumn::N;
+
+#[derive(N)]
+#[enumn(repr_type = u8)]
+enum E {
+    A,
+}
+
+#[derive(N)]
+#[repr(u8)]
+#[enumn(repr_type = i128)]
+enum F {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumn::N;
+
+#[derive(N)]
+#[repr(u8)]
+#[enumn(default_fields, set)]
+enum Message {
+    Ping,
+    Data(Vec<u8>),
+}
+
+fn main() {}
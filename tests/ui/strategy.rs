@@ -0,0 +1,31 @@
+use enumn::N;
+
+#[derive(N)]
+#[repr(u8)]
+#[enumn(strategy = "dense")]
+enum Gap {
+    A = 1,
+    B = 3,
+}
+
+#[derive(N)]
+#[enumn(strategy = "binary_search")]
+enum NoRepr {
+    A,
+}
+
+#[derive(N)]
+#[repr(u8, align(4))]
+#[enumn(strategy = "dense")]
+enum Aligned {
+    A,
+    B,
+}
+
+#[derive(N)]
+#[enumn(strategy = "hash")]
+enum Unknown {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use enumn::N;
+
+#[derive(N)]
+struct Struct {
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use enumn::N;
+
+#[derive(N)]
+union Union {
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumn::N;
+
+#[derive(N)]
+#[enumn(unknown)]
+enum E {
+    #[enumn(whatever)]
+    A,
+}
+
+fn main() {}